@@ -1,4 +1,4 @@
-from typing import List, Optional
+from typing import Any, Callable, Dict, List, Optional
 from enum import IntEnum
 
 class Layout(IntEnum):
@@ -11,6 +11,136 @@ class OpenAlexGraphType(IntEnum):
     KeywordCooccurrence = 1
     WorkCocitation = 2
     AuthorCocitation = 3
+    AuthorWork = 4
+    KeywordWork = 5
+
+class TraversalWeight(IntEnum):
+    Spc = 0
+    Splc = 1
+    Spnp = 2
+
+class ProjectionMethod(IntEnum):
+    Simple = 0
+    Newman = 1
+    Overlap = 2
+    Jaccard = 3
+
+class MotifCensus(IntEnum):
+    Triads = 0
+    Size3 = 1
+    Size4 = 2
+
+class NullModel(IntEnum):
+    Gnm = 0
+    DegreePreserving = 1
+
+class LinkPredictor(IntEnum):
+    CommonNeighbours = 0
+    Jaccard = 1
+    AdamicAdar = 2
+    ResourceAllocation = 3
+    PreferentialAttachment = 4
+    Katz = 5
+
+class SimilarityMethod(IntEnum):
+    SimRank = 0
+    Cosine = 1
+    Pearson = 2
+
+class WeightInterpretation(IntEnum):
+    Distance = 0
+    Strength = 1
+    Log = 2
+    Unweighted = 3
+
+class RemovalTarget(IntEnum):
+    Nodes = 0
+    Edges = 1
+
+class RemovalStrategy(IntEnum):
+    Random = 0
+    Degree = 1
+    Betweenness = 2
+
+class EdgeAggregation(IntEnum):
+    Sum = 0
+    Count = 1
+    Mean = 2
+
+class BackboneMethod(IntEnum):
+    DisparityFilter = 0
+    NoiseCorrected = 1
+    HighSalienceSkeleton = 2
+    GlobalThreshold = 3
+
+class VisitAction(IntEnum):
+    Continue = 0
+    Prune = 1
+    Stop = 2
+
+class RobustnessResult:
+    step: List[int]
+    removed: List[List[str]]
+    fraction_removed: List[float]
+    giant_component_size: List[int]
+    giant_component_fraction: List[float]
+    average_path_length: List[float]
+    r_index: float
+    def to_dict(self) -> Dict[str, list]: ...
+
+class DegreeType(IntEnum):
+    All = 0
+    In = 1
+    Out = 2
+    Undirected = 3
+
+class LikelihoodRatio:
+    ratio: float
+    normalized_ratio: float
+    p_value: float
+    parameters: Dict[str, float]
+
+class PowerLawFit:
+    xmin: int
+    alpha: float
+    ks_statistic: float
+    p_value: float
+    n: int
+    n_tail: int
+    lognormal: LikelihoodRatio
+    exponential: LikelihoodRatio
+    truncated_power_law: LikelihoodRatio
+
+class CascadeModel(IntEnum):
+    IndependentCascade = 0
+    LinearThreshold = 1
+
+class SeedSelection(IntEnum):
+    Greedy = 0
+    Celf = 1
+    CelfPlusPlus = 2
+    DegreeDiscount = 3
+
+class SimulationResult:
+    step: List[int]
+    susceptible: List[int]
+    infected: List[int]
+    recovered: List[int]
+    infection_times: Dict[str, int]
+    def to_dict(self) -> Dict[str, List[int]]: ...
+    def total_infected(self) -> int: ...
+
+class SimplePathIterator:
+    def __iter__(self) -> SimplePathIterator: ...
+    def __next__(self) -> List[str]: ...
+
+class EdgeIterator:
+    def __iter__(self) -> EdgeIterator: ...
+    def __next__(self) -> tuple[str, str, float, bool]: ...
+
+class WindowIterator:
+    def __iter__(self) -> WindowIterator: ...
+    def __next__(self) -> tuple[float, float, "Graph"]: ...
 
 class GraphStyle:
     node_color: str
@@ -43,9 +173,12 @@ class GraphStyle:
 class Graph:
     nodes: list[Node]
     build_time_ms: Optional[float]
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
     def add_node(self, label: str) -> None: ...
-    def create_connection(self, from_label: str, to_label: str, weight: float = 0., directed: bool = False) -> None: ...
+    def create_connection(self, from_label: str, to_label: str, weight: float = 0., directed: bool = False, start: Optional[float] = None, end: Optional[float] = None) -> None: ...
     def get_connections(self, from_name="from", to_name="to", use_id=False) -> list: ...
+    def edges(self) -> EdgeIterator: ...
     @staticmethod
     def from_adjacency_matrix(adj_matrix: list, directed: bool = False, custom_labels: list | None = None) -> "Graph": ...
     @staticmethod
@@ -67,11 +200,71 @@ class Graph:
     def get_node_stregth(self, node_label: str) -> dict: ...
     def get_degree_distribution(self) -> dict: ...
     def compute_entropy(self) -> dict: ...
+    def describe(self) -> Dict[str, Any]: ...
+    def node_metrics(self, metrics: List[str]) -> Dict[str, list]: ...
+    def power_law_fit(self, degree: DegreeType = DegreeType.All, xmin: Optional[int] = None, n_bootstrap: int = 100, seed: Optional[int] = None) -> PowerLawFit: ...
     def get_max_possible_entropy(self) -> float: ...
     def get_skewness(self) -> dict: ...
-    def dfs(self, start_node_label: str) -> List[str]: ...
-    def bfs(self, start_node_label: str) -> List[str]: ...
-    def dijkstra(self, start_node_label: str) -> dict: ...
+    def triad_census(self) -> Dict[str, float]: ...
+    def motif_census(self, motif_size: int = 3) -> Dict[str, float]: ...
+    def motif_z_scores(self, census: MotifCensus = MotifCensus.Triads, n_samples: int = 100, null_model: NullModel = NullModel.Gnm, seed: Optional[int] = None) -> Dict[str, float]: ...
+    def dfs(self, start_node_label: str, on_discover: Optional[Callable[[str, int], Optional[VisitAction]]] = None, on_finish: Optional[Callable[[str, int], Optional[VisitAction]]] = None, on_tree_edge: Optional[Callable[[str, str, float], Optional[VisitAction]]] = None, on_back_edge: Optional[Callable[[str, str, float], Optional[VisitAction]]] = None, on_cross_edge: Optional[Callable[[str, str, float], Optional[VisitAction]]] = None, max_depth: Optional[int] = None) -> List[str]: ...
+    def bfs(self, start_node_label: str, on_discover: Optional[Callable[[str, int], Optional[VisitAction]]] = None, on_finish: Optional[Callable[[str, int], Optional[VisitAction]]] = None, on_tree_edge: Optional[Callable[[str, str, float], Optional[VisitAction]]] = None, on_back_edge: Optional[Callable[[str, str, float], Optional[VisitAction]]] = None, on_cross_edge: Optional[Callable[[str, str, float], Optional[VisitAction]]] = None, max_depth: Optional[int] = None) -> List[str]: ...
+    def dijkstra(self, start_node_label: str, weights: WeightInterpretation | Callable[[float], float] = WeightInterpretation.Distance) -> dict: ...
+    def k_shortest_paths(self, source_label: str, target_label: str, k: int, weights: WeightInterpretation | Callable[[float], float] = WeightInterpretation.Distance) -> List[tuple[List[str], float]]: ...
+    def all_simple_paths(self, source_label: str, target_label: str, cutoff: Optional[int] = None) -> SimplePathIterator: ...
+    def is_dag(self) -> bool: ...
+    def find_cycle(self) -> Optional[List[str]]: ...
+    def simple_cycles(self) -> List[List[str]]: ...
+    def topological_sort(self) -> List[str]: ...
+    def dag_longest_path(self, weights: WeightInterpretation | Callable[[float], float] = WeightInterpretation.Unweighted) -> List[str]: ...
+    def ancestors(self, node_label: str) -> set[str]: ...
+    def descendants(self, node_label: str) -> set[str]: ...
+    def traversal_weights(self, weight_type: TraversalWeight = TraversalWeight.Spc) -> List[tuple[str, str, float]]: ...
+    def local_main_path(self, weight_type: TraversalWeight = TraversalWeight.Spc) -> "Graph": ...
+    def global_main_path(self, weight_type: TraversalWeight = TraversalWeight.Spc) -> "Graph": ...
+    def key_route_main_path(self, key_routes: int = 1, weight_type: TraversalWeight = TraversalWeight.Spc) -> "Graph": ...
+    def is_bipartite(self) -> bool: ...
+    def bipartite_sets(self) -> tuple[List[str], List[str]]: ...
+    def bipartite_projection(self, node_labels: List[str], method: ProjectionMethod = ProjectionMethod.Simple) -> "Graph": ...
+    def maximum_matching(self) -> List[tuple[str, str]]: ...
+    def is_isomorphic(self, other: "Graph", node_match: Optional[Callable[[Node, Node], bool]] = None, edge_match: Optional[Callable[[dict, dict], bool]] = None) -> bool: ...
+    def random_walks(self, walk_length: int = 10, walks_per_node: int = 1, weighted: bool = False, seed: Optional[int] = None) -> List[List[str]]: ...
+    def node2vec_walks(self, walk_length: int = 10, walks_per_node: int = 1, p: float = 1., q: float = 1., weighted: bool = False, seed: Optional[int] = None) -> List[List[str]]: ...
+    def link_prediction(self, method: LinkPredictor = LinkPredictor.AdamicAdar, candidates: Optional[List[tuple[str, str]]] = None, top_k: Optional[int] = None, beta: float = 0.05) -> List[tuple[str, str, float]]: ...
+    def similarity_matrix(self, method: SimilarityMethod = SimilarityMethod.SimRank, iterations: int = 10, decay: float = 0.8) -> List[List[float]]: ...
+    def most_similar(self, k: int = 5, method: SimilarityMethod = SimilarityMethod.SimRank, iterations: int = 10, decay: float = 0.8) -> Dict[str, List[tuple[str, float]]]: ...
+    def articulation_points(self) -> set[str]: ...
+    def bridges(self) -> List[tuple[str, str]]: ...
+    def biconnected_components(self) -> List[set[str]]: ...
+    def biconnected_component_edges(self) -> List[List[tuple[str, str]]]: ...
+    def maximal_cliques(self) -> List[List[str]]: ...
+    def clique_number(self) -> int: ...
+    def node_clique_number(self) -> Dict[str, int]: ...
+    def k_clique_communities(self, k: int) -> List[set[str]]: ...
+    def robustness(self, target: RemovalTarget = RemovalTarget.Nodes, strategy: RemovalStrategy = RemovalStrategy.Degree, adaptive: bool = False, max_removals: Optional[int] = None, seed: Optional[int] = None, weights: WeightInterpretation | Callable[[float], float] = WeightInterpretation.Unweighted) -> RobustnessResult: ...
+    def double_edge_swap(self, n_swaps: Optional[int] = None, seed: Optional[int] = None) -> "Graph": ...
+    def directed_edge_swap(self, n_swaps: Optional[int] = None, seed: Optional[int] = None) -> "Graph": ...
+    def significance(self, metric_fn: Callable[["Graph"], float], n_samples: int = 100, seed: Optional[int] = None) -> Dict[str, float]: ...
+    def random_node_sample(self, target_size: int, seed: Optional[int] = None) -> "Graph": ...
+    def random_edge_sample(self, target_size: int, seed: Optional[int] = None) -> "Graph": ...
+    def snowball_sample(self, target_size: int, depth: Optional[int] = None, start_node: Optional[str] = None, seed: Optional[int] = None) -> "Graph": ...
+    def random_walk_sample(self, target_size: int, restart_probability: float = 0.15, seed: Optional[int] = None) -> "Graph": ...
+    def forest_fire_sample(self, target_size: int, burn_probability: float = 0.7, seed: Optional[int] = None) -> "Graph": ...
+    def quotient_graph(self, groups: Dict[str, str], aggregation: EdgeAggregation = EdgeAggregation.Sum, self_loops: bool = False) -> "Graph": ...
+    def backbone(self, method: BackboneMethod = BackboneMethod.DisparityFilter, threshold: Optional[float] = None) -> tuple["Graph", List[tuple[str, str, float]]]: ...
+    def time_span(self) -> Optional[tuple[float, float]]: ...
+    def snapshot(self, time: float) -> "Graph": ...
+    def window(self, start: float, end: float) -> "Graph": ...
+    def sliding_windows(self, width: float, step: Optional[float] = None, start: Optional[float] = None, end: Optional[float] = None) -> WindowIterator: ...
+    def temporal_reachability(self, source_label: str, start_time: Optional[float] = None) -> Dict[str, float]: ...
+    def time_respecting_path(self, source_label: str, target_label: str, start_time: Optional[float] = None) -> Optional[List[tuple[str, str, float]]]: ...
+    def sir(self, seed_nodes: List[str], beta: float = 0.1, gamma: float = 0.05, max_steps: int = 100, weighted: bool = False, seed: Optional[int] = None) -> SimulationResult: ...
+    def sis(self, seed_nodes: List[str], beta: float = 0.1, gamma: float = 0.05, max_steps: int = 100, weighted: bool = False, seed: Optional[int] = None) -> SimulationResult: ...
+    def independent_cascade(self, seed_nodes: List[str], probability: float = 0.1, weighted: bool = False, max_steps: Optional[int] = None, seed: Optional[int] = None) -> SimulationResult: ...
+    def linear_threshold(self, seed_nodes: List[str], threshold: Optional[float] = None, weighted: bool = False, max_steps: Optional[int] = None, seed: Optional[int] = None) -> SimulationResult: ...
+    def influence_maximization(self, k: int, model: CascadeModel = CascadeModel.IndependentCascade, method: SeedSelection = SeedSelection.CelfPlusPlus, n_simulations: int = 100, probability: float = 0.1, weighted: bool = False, seed: Optional[int] = None) -> tuple[List[str], float]: ...
+    def subgraph_isomorphisms(self, pattern: "Graph", induced: bool = True, node_match: Optional[Callable[[Node, Node], bool]] = None, edge_match: Optional[Callable[[dict, dict], bool]] = None) -> List[Dict[str, str]]: ...
     def output_svg(self, layout: Layout = Layout.Random, override_positions: bool = False, style: Optional[GraphStyle] = None) -> str: ...
     def output_html(self, file_name: str, layout: Layout = Layout.Random, override_positions: bool = False, style: Optional[GraphStyle] = None) -> None: ...
     def output_net_file(self, file_name: str) -> None: ...
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use crate::_Graph;
+
+pub fn node_labels(graph: &_Graph) -> Vec<String> {
+    let labels: Vec<String> = graph.nodes
+        .iter()
+        .map(|n| n.borrow().label.clone())
+        .collect();
+
+    return labels;
+}
+
+pub fn label_positions(graph: &_Graph) -> HashMap<String, usize> {
+    let positions: HashMap<String, usize> = graph.nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.borrow().label.clone(), i))
+        .collect();
+
+    return positions;
+}
+
+pub fn position_of(graph: &_Graph, label: &str) -> usize {
+    for (i, n) in graph.nodes.iter().enumerate() {
+        if n.borrow().label == label {
+            return i;
+        }
+    }
+
+    panic!("Node '{}' not found", label);
+}
+
+/*
+ * View followed by dfs and bfs: every connection is followed in the
+ * direction it was created, using the position of the node inside
+ * graph.nodes as its index.
+ */
+pub fn weighted_successors(graph: &_Graph) -> Vec<Vec<(usize, f32)>> {
+    let mut successors: Vec<Vec<(usize, f32)>> = vec![Vec::new(); graph.nodes.len()];
+
+    for edge in graph.edges() {
+        successors[edge.from].push((edge.to, edge.weight));
+    }
+
+    return successors;
+}
+
+pub fn successors(graph: &_Graph) -> Vec<Vec<usize>> {
+    let successors: Vec<Vec<usize>> = weighted_successors(graph)
+        .into_iter()
+        .map(|adj| {
+            let mut targets: Vec<usize> = Vec::new();
+            for (j, _) in adj {
+                if !targets.contains(&j) {
+                    targets.push(j);
+                }
+            }
+            targets
+        })
+        .collect();
+
+    return successors;
+}
+
+pub fn predecessors(successors: &Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); successors.len()];
+
+    for (i, adj) in successors.iter().enumerate() {
+        for j in adj {
+            predecessors[*j].push(i);
+        }
+    }
+
+    return predecessors;
+}
+
+/*
+ * Undirected view of the graph: every connection links both of its ends,
+ * ignoring the 'directed' flag. Self-loops and repeated connections are dropped.
+ */
+pub fn neighbours(graph: &_Graph) -> Vec<Vec<usize>> {
+    let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); graph.nodes.len()];
+
+    for (i, adj) in successors(graph).into_iter().enumerate() {
+        for j in adj {
+            if i != j {
+                neighbours[i].push(j);
+                neighbours[j].push(i);
+            }
+        }
+    }
+
+    for adj in neighbours.iter_mut() {
+        adj.sort();
+        adj.dedup();
+    }
+
+    return neighbours;
+}
+
+/*
+ * Same view as generate_adjacency_matrix, but as lists: directed connections
+ * go one way and undirected connections both ways, keeping the weights.
+ */
+pub fn weighted_adjacency(graph: &_Graph) -> Vec<Vec<(usize, f32)>> {
+    let mut adjacency: Vec<Vec<(usize, f32)>> = vec![Vec::new(); graph.nodes.len()];
+
+    for edge in graph.edges() {
+        adjacency[edge.from].push((edge.to, edge.weight));
+        if !edge.directed && edge.from != edge.to {
+            adjacency[edge.to].push((edge.from, edge.weight));
+        }
+    }
+
+    return adjacency;
+}
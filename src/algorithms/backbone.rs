@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::node_labels;
+use crate::algorithms::paths::{dijkstra_tree, InvalidLengthError, PathView, WeightInterpretation};
+use crate::algorithms::subgraph::{edge_subgraph, graph_edges};
+use crate::graph_core::edges::EdgeRef;
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum BackboneMethod {
+    DisparityFilter,
+    NoiseCorrected,
+    HighSalienceSkeleton,
+    GlobalThreshold
+}
+
+/*
+ * Threshold used when none is given: significance level for the disparity
+ * filter, number of standard deviations for the noise-corrected backbone,
+ * share of shortest path trees for the salience and mean weight for the
+ * global threshold.
+ */
+fn default_threshold(edges: &Vec<EdgeRef>, method: &BackboneMethod) -> f64 {
+    match method {
+        BackboneMethod::DisparityFilter => return 0.05,
+        BackboneMethod::NoiseCorrected => return 1.64,
+        BackboneMethod::HighSalienceSkeleton => return 0.5,
+        BackboneMethod::GlobalThreshold => {
+            if edges.is_empty() {
+                return 0.;
+            }
+            return edges.iter().map(|e| e.weight as f64).sum::<f64>() / edges.len() as f64;
+        }
+    }
+}
+
+/*
+ * Outgoing and incoming strength and degree of every node. Undirected
+ * connections count both ways.
+ */
+fn strengths(size: usize, edges: &Vec<EdgeRef>) -> (Vec<f64>, Vec<f64>, Vec<usize>, Vec<usize>) {
+    let mut out_strength: Vec<f64> = vec![0.; size];
+    let mut in_strength: Vec<f64> = vec![0.; size];
+    let mut out_degree: Vec<usize> = vec![0; size];
+    let mut in_degree: Vec<usize> = vec![0; size];
+
+    for edge in edges {
+        let weight = edge.weight as f64;
+        out_strength[edge.from] += weight;
+        in_strength[edge.to] += weight;
+        out_degree[edge.from] += 1;
+        in_degree[edge.to] += 1;
+
+        if !edge.directed {
+            out_strength[edge.to] += weight;
+            in_strength[edge.from] += weight;
+            out_degree[edge.to] += 1;
+            in_degree[edge.from] += 1;
+        }
+    }
+
+    return (out_strength, in_strength, out_degree, in_degree);
+}
+
+/*
+ * Disparity filter (Serrano et al.): under the null hypothesis the
+ * strength of a node is split uniformly at random among its k connections,
+ * so a connection carrying a share p of it has p-value (1 - p)^(k - 1).
+ * Each connection is tested from both of its ends (from the source's
+ * outgoing and the target's incoming connections when directed) and keeps
+ * the smallest p-value. Nodes with a single connection cannot be tested.
+ */
+fn disparity_filter(size: usize, edges: &Vec<EdgeRef>) -> Vec<f64> {
+    let (out_strength, in_strength, out_degree, in_degree) = strengths(size, edges);
+
+    let p_value = |weight: f64, strength: f64, degree: usize| -> f64 {
+        if degree < 2 || strength <= 0. {
+            return 1.;
+        }
+        return (1. - weight / strength).max(0.).powi(degree as i32 - 1);
+    };
+
+    return edges
+        .iter()
+        .map(|edge| {
+            let w = edge.weight as f64;
+            return p_value(w, out_strength[edge.from], out_degree[edge.from]).min(p_value(w, in_strength[edge.to], in_degree[edge.to]));
+        })
+        .collect();
+}
+
+/*
+ * Noise-corrected backbone (Coscia & Neffke): compares each weight with
+ * the one expected from the strengths of its ends, using a binomial null
+ * model with a Beta prior. The score is the transformed lift
+ * (k N_ij - 1) / (k N_ij + 1), k = N / (N_i N_j), divided by its standard
+ * deviation.
+ */
+fn noise_corrected(size: usize, edges: &Vec<EdgeRef>) -> Vec<f64> {
+    let (out_strength, in_strength, _, _) = strengths(size, edges);
+    let total: f64 = out_strength.iter().sum();
+
+    return edges
+        .iter()
+        .map(|edge| {
+            let nij = edge.weight as f64;
+            let ni = out_strength[edge.from];
+            let nj = in_strength[edge.to];
+
+            let kappa = total / (ni * nj);
+            let score = (kappa * nij - 1.) / (kappa * nij + 1.);
+
+            let prior_mean = ni * nj / (total * total);
+            let prior_variance = ni * nj * (total - ni) * (total - nj) / (total.powi(4) * (total - 1.));
+            let alpha_prior = prior_mean * prior_mean / prior_variance * (1. - prior_mean) - prior_mean;
+            let beta_prior = prior_mean / prior_variance * (1. - prior_mean * prior_mean) - (1. - prior_mean);
+
+            let alpha_post = alpha_prior + nij;
+            let beta_post = total - nij + beta_prior;
+            let expected = alpha_post / (alpha_post + beta_post);
+            let variance_nij = expected * (1. - expected) * total;
+
+            let d = 1. / (ni * nj) - total * (ni + nj) / (ni * nj).powi(2);
+            let variance = variance_nij * (2. * (kappa + nij * d) / (kappa * nij + 1.).powi(2)).powi(2);
+            let std = variance.sqrt();
+
+            if !std.is_finite() || std == 0. {
+                return if score > 0. { f64::INFINITY } else { 0. };
+            }
+            return score / std;
+        })
+        .collect();
+}
+
+/*
+ * High-salience skeleton (Grady et al.): share of the shortest path trees,
+ * one rooted at every node, that contain the connection. Lengths are
+ * 1 / weight, so strong connections are short; negative weights are an
+ * error.
+ */
+fn salience(graph: &_Graph, edges: &Vec<EdgeRef>) -> Result<Vec<f64>, InvalidLengthError> {
+    let view = PathView::new(graph, &WeightInterpretation::Strength, None)?;
+    let size = view.size();
+    let blocked_nodes: Vec<bool> = vec![false; size];
+    let blocked_arcs: HashSet<(usize, usize)> = HashSet::new();
+    let mut counts: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for root in 0..size {
+        let (_, previous) = dijkstra_tree(&view, root, &blocked_nodes, &blocked_arcs);
+
+        for (v, parent) in previous.iter().enumerate() {
+            if let Some(u) = parent {
+                *counts.entry((*u, v)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let scores: Vec<f64> = edges
+        .iter()
+        .map(|edge| {
+            let mut count = counts.get(&(edge.from, edge.to)).copied().unwrap_or(0);
+            if !edge.directed && edge.from != edge.to {
+                count += counts.get(&(edge.to, edge.from)).copied().unwrap_or(0);
+            }
+            return count as f64 / size as f64;
+        })
+        .collect();
+
+    return Ok(scores);
+}
+
+/*
+ * Scores every connection of the graph with the chosen method and keeps
+ * the significant ones: p-value at most 'threshold' for the disparity
+ * filter, score at least 'threshold' for the other methods. Returns the
+ * backbone (only the nodes touching a kept connection) and the score of
+ * every connection, in the order they are stored.
+ */
+pub fn backbone(
+    graph: &_Graph,
+    method: &BackboneMethod,
+    threshold: Option<f64>
+) -> Result<(_Graph, Vec<(String, String, f64)>), InvalidLengthError> {
+    let labels = node_labels(graph);
+    let edges = graph_edges(graph);
+    let size = graph.nodes.len();
+    let threshold = threshold.unwrap_or_else(|| default_threshold(&edges, method));
+
+    let scores: Vec<f64> = match method {
+        BackboneMethod::DisparityFilter => disparity_filter(size, &edges),
+        BackboneMethod::NoiseCorrected => noise_corrected(size, &edges),
+        BackboneMethod::HighSalienceSkeleton => salience(graph, &edges)?,
+        BackboneMethod::GlobalThreshold => edges.iter().map(|e| e.weight as f64).collect(),
+    };
+
+    let kept: Vec<EdgeRef> = edges
+        .iter()
+        .zip(&scores)
+        .filter(|(_, score)| match method {
+            BackboneMethod::DisparityFilter => **score <= threshold,
+            _ => **score >= threshold,
+        })
+        .map(|(edge, _)| *edge)
+        .collect();
+
+    let significance: Vec<(String, String, f64)> = edges
+        .iter()
+        .zip(scores)
+        .map(|(edge, score)| (labels[edge.from].clone(), labels[edge.to].clone(), score))
+        .collect();
+
+    return Ok((edge_subgraph(graph, &kept), significance));
+}
@@ -0,0 +1,283 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::{label_positions, neighbours, node_labels};
+use crate::algorithms::subgraph::build_subgraph;
+use crate::graph_core::edges::EdgeRef;
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum ProjectionMethod {
+    Simple,
+    Newman,
+    Overlap,
+    Jaccard
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotBipartiteError;
+
+impl fmt::Display for NotBipartiteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Graph is not bipartite")
+    }
+}
+
+impl std::error::Error for NotBipartiteError {}
+
+/*
+ * BFS two-colouring of the undirected view of the graph. Each component
+ * starts with colour 0 on its first node (in insertion order).
+ */
+fn two_colouring(adjacency: &Vec<Vec<usize>>) -> Option<Vec<u8>> {
+    let size = adjacency.len();
+    let mut colour: Vec<Option<u8>> = vec![None; size];
+
+    for root in 0..size {
+        if colour[root].is_some() {
+            continue;
+        }
+
+        colour[root] = Some(0);
+        let mut q: VecDeque<usize> = VecDeque::from([root]);
+
+        while let Some(v) = q.pop_front() {
+            let c = colour[v].unwrap();
+
+            for w in &adjacency[v] {
+                match colour[*w] {
+                    None => {
+                        colour[*w] = Some(1 - c);
+                        q.push_back(*w);
+                    },
+                    Some(other) => {
+                        if other == c {
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    return Some(colour.into_iter().map(|c| c.unwrap()).collect());
+}
+
+pub fn is_bipartite(graph: &_Graph) -> bool {
+    return two_colouring(&neighbours(graph)).is_some();
+}
+
+pub fn bipartite_sets(graph: &_Graph) -> Result<(Vec<String>, Vec<String>), NotBipartiteError> {
+    let labels = node_labels(graph);
+    let colour = two_colouring(&neighbours(graph)).ok_or(NotBipartiteError)?;
+
+    let mut top: Vec<String> = Vec::new();
+    let mut bottom: Vec<String> = Vec::new();
+
+    for (i, c) in colour.iter().enumerate() {
+        if *c == 0 {
+            top.push(labels[i].clone());
+        } else {
+            bottom.push(labels[i].clone());
+        }
+    }
+
+    return Ok((top, bottom));
+}
+
+/*
+ * Smallest interval covering both, None when either has no time.
+ */
+fn merge_spans(a: Option<(f64, f64)>, b: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match (a, b) {
+        (Some((s1, e1)), Some((s2, e2))) => return Some((s1.min(s2), e1.max(e2))),
+        _ => return None,
+    }
+}
+
+/*
+ * Projects the graph onto 'node_labels'. Two of those nodes are linked when
+ * they share at least one neighbour outside the set, with weight:
+ *  - Simple:  number of shared neighbours
+ *  - Newman:  sum of 1 / (degree - 1) over the shared neighbours
+ *  - Overlap: shared / min(degree of each node)
+ *  - Jaccard: shared / size of the union of both neighbourhoods
+ * When every connection through the shared neighbours has a time, the
+ * projected connection spans from the earliest start to the latest end of
+ * those times; otherwise it has no time.
+ */
+pub fn bipartite_projection(graph: &_Graph, node_labels: &Vec<String>, method: &ProjectionMethod) -> _Graph {
+    let positions = label_positions(graph);
+    let adjacency = neighbours(graph);
+
+    let mut nodes: Vec<usize> = node_labels
+        .iter()
+        .map(|label| *positions.get(label).expect("Node not found"))
+        .collect();
+    nodes.sort();
+    nodes.dedup();
+
+    let in_set: HashSet<usize> = nodes.iter().copied().collect();
+    let mut shared: HashMap<(usize, usize), f64> = HashMap::new();
+    let mut spans: HashMap<(usize, usize), Option<(f64, f64)>> = HashMap::new();
+    let mut pair_spans: HashMap<(usize, usize), Option<(f64, f64)>> = HashMap::new();
+
+    for edge in graph.edges() {
+        let key = (edge.from.min(edge.to), edge.from.max(edge.to));
+        let span = pair_spans.get(&key).map_or(edge.time, |s| merge_spans(*s, edge.time));
+        pair_spans.insert(key, span);
+    }
+
+    for k in 0..adjacency.len() {
+        if in_set.contains(&k) {
+            continue;
+        }
+
+        let members: Vec<usize> = adjacency[k].iter().copied().filter(|v| in_set.contains(v)).collect();
+        let contribution = match method {
+            ProjectionMethod::Newman => {
+                if adjacency[k].len() < 2 {
+                    continue;
+                }
+                1. / (adjacency[k].len() - 1) as f64
+            },
+            _ => 1.
+        };
+
+        for a in 0..members.len() {
+            for b in (a + 1)..members.len() {
+                let key = (members[a].min(members[b]), members[a].max(members[b]));
+                *shared.entry(key).or_insert(0.) += contribution;
+
+                let through = merge_spans(
+                    pair_spans[&(members[a].min(k), members[a].max(k))],
+                    pair_spans[&(members[b].min(k), members[b].max(k))]
+                );
+                let span = spans.get(&key).map_or(through, |s| merge_spans(*s, through));
+                spans.insert(key, span);
+            }
+        }
+    }
+
+    let outside_degree = |v: usize| -> f64 {
+        return adjacency[v].iter().filter(|w| !in_set.contains(w)).count() as f64;
+    };
+
+    let mut edges: Vec<EdgeRef> = shared
+        .into_iter()
+        .map(|((u, v), count)| {
+            let weight = match method {
+                ProjectionMethod::Simple | ProjectionMethod::Newman => count,
+                ProjectionMethod::Overlap => count / outside_degree(u).min(outside_degree(v)),
+                ProjectionMethod::Jaccard => count / (outside_degree(u) + outside_degree(v) - count),
+            };
+            EdgeRef { from: u, to: v, weight: weight as f32, directed: false, time: spans[&(u, v)] }
+        })
+        .collect();
+    edges.sort_by(|a, b| (a.from, a.to).cmp(&(b.from, b.to)));
+
+    return build_subgraph(graph, &nodes, &edges);
+}
+
+/*
+ * Hopcroft–Karp on the two sides given by the two-colouring: BFS builds the
+ * layers of shortest augmenting paths, DFS augments along them.
+ */
+pub fn maximum_matching(graph: &_Graph) -> Result<Vec<(String, String)>, NotBipartiteError> {
+    let labels = node_labels(graph);
+    let adjacency = neighbours(graph);
+    let colour = two_colouring(&adjacency).ok_or(NotBipartiteError)?;
+    let size = adjacency.len();
+
+    let left: Vec<usize> = (0..size).filter(|v| colour[*v] == 0).collect();
+    let mut mate: Vec<Option<usize>> = vec![None; size];
+    let mut layer: Vec<usize> = vec![usize::MAX; size];
+
+    /*
+     * Iterative DFS from a free left node. Each stack entry is a left node of
+     * the current path and the next neighbour to try; the neighbour tried
+     * before it is the one the path goes through. Dead ends leave the layers.
+     */
+    fn augment(root: usize, adjacency: &Vec<Vec<usize>>, mate: &mut Vec<Option<usize>>, layer: &mut Vec<usize>) -> bool {
+        let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+
+        while let Some(&(v, next)) = stack.last() {
+            if next >= adjacency[v].len() {
+                layer[v] = usize::MAX;
+                stack.pop();
+                continue;
+            }
+
+            let w = adjacency[v][next];
+            stack.last_mut().unwrap().1 += 1;
+
+            match mate[w] {
+                None => {
+                    for (u, tried) in &stack {
+                        let x = adjacency[*u][*tried - 1];
+                        mate[*u] = Some(x);
+                        mate[x] = Some(*u);
+                    }
+                    return true;
+                },
+                Some(u) => {
+                    if layer[u] == layer[v] + 1 {
+                        stack.push((u, 0));
+                    }
+                }
+            }
+        }
+
+        return false;
+    }
+
+    loop {
+        let mut q: VecDeque<usize> = VecDeque::new();
+        for v in &left {
+            if mate[*v].is_none() {
+                layer[*v] = 0;
+                q.push_back(*v);
+            } else {
+                layer[*v] = usize::MAX;
+            }
+        }
+
+        let mut found_free = false;
+        while let Some(v) = q.pop_front() {
+            for w in &adjacency[v] {
+                match mate[*w] {
+                    None => found_free = true,
+                    Some(u) => {
+                        if layer[u] == usize::MAX {
+                            layer[u] = layer[v] + 1;
+                            q.push_back(u);
+                        }
+                    }
+                }
+            }
+        }
+
+        if !found_free {
+            break;
+        }
+
+        let mut augmented = false;
+        for v in &left {
+            if mate[*v].is_none() && augment(*v, &adjacency, &mut mate, &mut layer) {
+                augmented = true;
+            }
+        }
+
+        if !augmented {
+            break;
+        }
+    }
+
+    let matching: Vec<(String, String)> = left
+        .iter()
+        .filter_map(|v| mate[*v].map(|w| (labels[*v].clone(), labels[w].clone())))
+        .collect();
+
+    return Ok(matching);
+}
@@ -0,0 +1,71 @@
+use std::collections::{HashMap, VecDeque};
+
+/*
+ * Brandes' algorithm on an unweighted undirected adjacency: one BFS per
+ * source counts shortest paths, then dependencies are accumulated in
+ * reverse BFS order. Returns node and edge (u < v) betweenness, each pair
+ * counted once.
+ */
+pub fn betweenness(adjacency: &Vec<Vec<usize>>) -> (Vec<f64>, HashMap<(usize, usize), f64>) {
+    let size = adjacency.len();
+    let mut node_scores: Vec<f64> = vec![0.; size];
+    let mut edge_scores: HashMap<(usize, usize), f64> = HashMap::new();
+
+    for (v, adj) in adjacency.iter().enumerate() {
+        for w in adj {
+            if v < *w {
+                edge_scores.insert((v, *w), 0.);
+            }
+        }
+    }
+
+    for source in 0..size {
+        let mut order: Vec<usize> = Vec::new();
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut paths: Vec<f64> = vec![0.; size];
+        let mut distance: Vec<i64> = vec![-1; size];
+
+        paths[source] = 1.;
+        distance[source] = 0;
+        let mut q: VecDeque<usize> = VecDeque::from([source]);
+
+        while let Some(v) = q.pop_front() {
+            order.push(v);
+
+            for w in &adjacency[v] {
+                if distance[*w] < 0 {
+                    distance[*w] = distance[v] + 1;
+                    q.push_back(*w);
+                }
+
+                if distance[*w] == distance[v] + 1 {
+                    paths[*w] += paths[v];
+                    predecessors[*w].push(v);
+                }
+            }
+        }
+
+        let mut dependency: Vec<f64> = vec![0.; size];
+        while let Some(w) = order.pop() {
+            for v in &predecessors[w] {
+                let share = paths[*v] / paths[w] * (1. + dependency[w]);
+                dependency[*v] += share;
+                *edge_scores.get_mut(&((*v).min(w), (*v).max(w))).unwrap() += share;
+            }
+
+            if w != source {
+                node_scores[w] += dependency[w];
+            }
+        }
+    }
+
+    // Every pair was counted from both of its ends
+    for score in node_scores.iter_mut() {
+        *score /= 2.;
+    }
+    for score in edge_scores.values_mut() {
+        *score /= 2.;
+    }
+
+    return (node_scores, edge_scores);
+}
@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+use crate::_Graph;
+use crate::algorithms::adjacency::{neighbours, node_labels};
+
+/*
+ * Bron–Kerbosch with Tomita pivoting: the pivot is the node of P ∪ X with
+ * the most neighbours in P, and only the nodes of P outside its
+ * neighbourhood are branched on.
+ */
+fn bron_kerbosch(
+    r: &mut Vec<usize>,
+    p: HashSet<usize>,
+    x: HashSet<usize>,
+    adj: &Vec<HashSet<usize>>,
+    cliques: &mut Vec<Vec<usize>>
+) {
+    if p.is_empty() {
+        if x.is_empty() {
+            let mut clique = r.clone();
+            clique.sort();
+            cliques.push(clique);
+        }
+        return;
+    }
+
+    let pivot = p
+        .iter()
+        .chain(x.iter())
+        .copied()
+        .max_by_key(|u| (p.intersection(&adj[*u]).count(), usize::MAX - *u))
+        .unwrap();
+
+    let mut candidates: Vec<usize> = p.difference(&adj[pivot]).copied().collect();
+    candidates.sort();
+
+    let mut p = p;
+    let mut x = x;
+
+    for v in candidates {
+        let next_p: HashSet<usize> = p.intersection(&adj[v]).copied().collect();
+        let next_x: HashSet<usize> = x.intersection(&adj[v]).copied().collect();
+
+        r.push(v);
+        bron_kerbosch(r, next_p, next_x, adj, cliques);
+        r.pop();
+
+        p.remove(&v);
+        x.insert(v);
+    }
+}
+
+/*
+ * The outer loop goes through the nodes in degeneracy order (repeatedly
+ * taking the node with the fewest remaining neighbours), which keeps the
+ * candidate sets of each top level call small on sparse graphs.
+ */
+fn maximal_clique_indices(adj: &Vec<HashSet<usize>>) -> Vec<Vec<usize>> {
+    let size = adj.len();
+    let mut remaining_degree: Vec<usize> = adj.iter().map(|a| a.len()).collect();
+    let mut removed: Vec<bool> = vec![false; size];
+    let mut order: Vec<usize> = Vec::new();
+
+    for _ in 0..size {
+        let v = (0..size)
+            .filter(|v| !removed[*v])
+            .min_by_key(|v| (remaining_degree[*v], *v))
+            .unwrap();
+
+        removed[v] = true;
+        order.push(v);
+        for w in &adj[v] {
+            if !removed[*w] {
+                remaining_degree[*w] -= 1;
+            }
+        }
+    }
+
+    let mut rank: Vec<usize> = vec![0; size];
+    for (i, v) in order.iter().enumerate() {
+        rank[*v] = i;
+    }
+
+    let mut cliques: Vec<Vec<usize>> = Vec::new();
+
+    for v in order {
+        let p: HashSet<usize> = adj[v].iter().copied().filter(|w| rank[*w] > rank[v]).collect();
+        let x: HashSet<usize> = adj[v].iter().copied().filter(|w| rank[*w] < rank[v]).collect();
+
+        bron_kerbosch(&mut vec![v], p, x, adj, &mut cliques);
+    }
+
+    cliques.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+
+    return cliques;
+}
+
+fn neighbour_sets(graph: &_Graph) -> Vec<HashSet<usize>> {
+    return neighbours(graph).into_iter().map(|adj| adj.into_iter().collect()).collect();
+}
+
+/*
+ * Every maximal clique of the undirected view of the graph, largest first.
+ * Isolated nodes are cliques of size one.
+ */
+pub fn maximal_cliques(graph: &_Graph) -> Vec<Vec<String>> {
+    let labels = node_labels(graph);
+
+    let cliques: Vec<Vec<String>> = maximal_clique_indices(&neighbour_sets(graph))
+        .into_iter()
+        .map(|clique| clique.into_iter().map(|v| labels[v].clone()).collect())
+        .collect();
+
+    return cliques;
+}
+
+pub fn clique_number(graph: &_Graph) -> usize {
+    return maximal_clique_indices(&neighbour_sets(graph)).first().map(|c| c.len()).unwrap_or(0);
+}
+
+/*
+ * Size of the largest clique containing each node.
+ */
+pub fn node_clique_number(graph: &_Graph) -> HashMap<String, usize> {
+    let labels = node_labels(graph);
+    let mut sizes: Vec<usize> = vec![0; labels.len()];
+
+    for clique in maximal_clique_indices(&neighbour_sets(graph)) {
+        for v in &clique {
+            sizes[*v] = sizes[*v].max(clique.len());
+        }
+    }
+
+    return labels.into_iter().zip(sizes).collect();
+}
+
+/*
+ * Clique percolation (Palla et al.): two k-cliques are adjacent when they
+ * share k - 1 nodes, and a community is the union of a chain of adjacent
+ * k-cliques. Working on maximal cliques of at least k nodes gives the same
+ * communities, since two of them share k - 1 nodes exactly when some of
+ * their k-cliques do. Communities can overlap.
+ */
+pub fn k_clique_communities(graph: &_Graph, k: usize) -> Vec<HashSet<String>> {
+    if k < 2 {
+        panic!("k must be at least 2");
+    }
+
+    let labels = node_labels(graph);
+    let cliques: Vec<HashSet<usize>> = maximal_clique_indices(&neighbour_sets(graph))
+        .into_iter()
+        .filter(|c| c.len() >= k)
+        .map(|c| c.into_iter().collect())
+        .collect();
+
+    let mut parent: Vec<usize> = (0..cliques.len()).collect();
+
+    fn find(parent: &mut Vec<usize>, i: usize) -> usize {
+        let mut root = i;
+        while parent[root] != root {
+            root = parent[root];
+        }
+
+        let mut current = i;
+        while parent[current] != root {
+            let next = parent[current];
+            parent[current] = root;
+            current = next;
+        }
+
+        return root;
+    }
+
+    for a in 0..cliques.len() {
+        for b in (a + 1)..cliques.len() {
+            if cliques[a].intersection(&cliques[b]).count() >= k - 1 {
+                let root_a = find(&mut parent, a);
+                let root_b = find(&mut parent, b);
+                parent[root_b] = root_a;
+            }
+        }
+    }
+
+    let mut groups: Vec<(usize, HashSet<usize>)> = Vec::new();
+    for (i, clique) in cliques.iter().enumerate() {
+        let root = find(&mut parent, i);
+        match groups.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, members)) => members.extend(clique.iter().copied()),
+            None => groups.push((root, clique.clone())),
+        }
+    }
+
+    let communities: Vec<HashSet<String>> = groups
+        .into_iter()
+        .map(|(_, members)| members.into_iter().map(|v| labels[v].clone()).collect())
+        .collect();
+
+    return communities;
+}
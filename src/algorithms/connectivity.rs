@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use crate::_Graph;
+use crate::algorithms::adjacency::node_labels;
+
+/*
+ * Result of the Hopcroft–Tarjan DFS on the undirected view of the graph.
+ * Components are lists of edges (u < v) so that nodes shared between
+ * components (the articulation points) are kept in each of them.
+ */
+struct Biconnectivity {
+    articulation_points: Vec<usize>,
+    bridges: Vec<(usize, usize)>,
+    components: Vec<Vec<(usize, usize)>>
+}
+
+/*
+ * Undirected view that keeps parallel connections: for every node, its
+ * (neighbour, connection id) pairs sorted by neighbour. Self-loops are left
+ * out since they never connect anything.
+ */
+fn multi_adjacency(graph: &_Graph) -> Vec<Vec<(usize, usize)>> {
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); graph.nodes.len()];
+
+    for (id, edge) in graph.edges().enumerate() {
+        if edge.from != edge.to {
+            adjacency[edge.from].push((edge.to, id));
+            adjacency[edge.to].push((edge.from, id));
+        }
+    }
+
+    for adj in adjacency.iter_mut() {
+        adj.sort();
+    }
+
+    return adjacency;
+}
+
+/*
+ * Iterative DFS keeping, for every node, its discovery time and low-link
+ * (earliest discovery time reachable through one back edge). An edge
+ * (parent, child) is a bridge when low[child] > disc[parent], and parent
+ * separates the graph when low[child] >= disc[parent]. Only the connection
+ * used to reach a node is skipped when looking at its neighbours, so a
+ * parallel connection to the parent counts as a back edge.
+ */
+fn biconnectivity(adjacency: &Vec<Vec<(usize, usize)>>) -> Biconnectivity {
+    let size = adjacency.len();
+    let mut disc: Vec<usize> = vec![usize::MAX; size];
+    let mut low: Vec<usize> = vec![0; size];
+    let mut is_articulation: Vec<bool> = vec![false; size];
+    let mut bridges: Vec<(usize, usize)> = Vec::new();
+    let mut components: Vec<Vec<(usize, usize)>> = Vec::new();
+    // (u, v, connection id) of the edges of the components still open
+    let mut edge_stack: Vec<(usize, usize, usize)> = Vec::new();
+    let mut time = 0;
+
+    let ordered = |a: usize, b: usize| (a.min(b), a.max(b));
+
+    for root in 0..size {
+        if disc[root] != usize::MAX {
+            continue;
+        }
+
+        disc[root] = time;
+        low[root] = time;
+        time += 1;
+
+        let mut root_children = 0;
+        // (node, parent, connection to the parent, index of the next neighbour to visit)
+        let mut stack: Vec<(usize, usize, usize, usize)> = vec![(root, usize::MAX, usize::MAX, 0)];
+
+        while let Some((v, parent, parent_edge, next)) = stack.pop() {
+            if next < adjacency[v].len() {
+                stack.push((v, parent, parent_edge, next + 1));
+                let (w, id) = adjacency[v][next];
+
+                if id == parent_edge {
+                    continue;
+                }
+
+                if disc[w] == usize::MAX {
+                    disc[w] = time;
+                    low[w] = time;
+                    time += 1;
+                    edge_stack.push((v.min(w), v.max(w), id));
+                    stack.push((w, v, id, 0));
+
+                    if v == root {
+                        root_children += 1;
+                    }
+                } else if disc[w] < disc[v] {
+                    low[v] = low[v].min(disc[w]);
+                    edge_stack.push((v.min(w), v.max(w), id));
+                }
+
+                continue;
+            }
+
+            if parent == usize::MAX {
+                continue;
+            }
+
+            low[parent] = low[parent].min(low[v]);
+
+            if low[v] > disc[parent] {
+                bridges.push(ordered(parent, v));
+            }
+
+            if low[v] >= disc[parent] {
+                if parent != root {
+                    is_articulation[parent] = true;
+                }
+
+                // Parallel connections share the pair, so stop at the tree edge itself
+                let mut component: Vec<(usize, usize)> = Vec::new();
+                while let Some((a, b, id)) = edge_stack.pop() {
+                    component.push((a, b));
+                    if id == parent_edge {
+                        break;
+                    }
+                }
+                component.sort();
+                component.dedup();
+                components.push(component);
+            }
+        }
+
+        if root_children > 1 {
+            is_articulation[root] = true;
+        }
+    }
+
+    bridges.sort();
+
+    return Biconnectivity {
+        articulation_points: (0..size).filter(|v| is_articulation[*v]).collect(),
+        bridges: bridges,
+        components: components
+    };
+}
+
+/*
+ * Nodes whose removal increases the number of connected components.
+ * Connections are treated as undirected.
+ */
+pub fn articulation_points(graph: &_Graph) -> HashSet<String> {
+    let labels = node_labels(graph);
+    let result = biconnectivity(&multi_adjacency(graph));
+
+    return result.articulation_points.into_iter().map(|v| labels[v].clone()).collect();
+}
+
+/*
+ * Connections whose removal increases the number of connected components,
+ * as (from, to) pairs following the node insertion order.
+ */
+pub fn bridges(graph: &_Graph) -> Vec<(String, String)> {
+    let labels = node_labels(graph);
+    let result = biconnectivity(&multi_adjacency(graph));
+
+    return result.bridges.into_iter().map(|(u, v)| (labels[u].clone(), labels[v].clone())).collect();
+}
+
+/*
+ * Maximal subgraphs that stay connected after removing any single node.
+ * Each component is returned as the set of its nodes; articulation points
+ * belong to every component they join. Isolated nodes are not part of any.
+ */
+pub fn biconnected_components(graph: &_Graph) -> Vec<HashSet<String>> {
+    let labels = node_labels(graph);
+    let result = biconnectivity(&multi_adjacency(graph));
+
+    let components: Vec<HashSet<String>> = result.components
+        .iter()
+        .map(|edges| {
+            edges
+                .iter()
+                .flat_map(|(u, v)| [labels[*u].clone(), labels[*v].clone()])
+                .collect()
+        })
+        .collect();
+
+    return components;
+}
+
+pub fn biconnected_component_edges(graph: &_Graph) -> Vec<Vec<(String, String)>> {
+    let labels = node_labels(graph);
+    let result = biconnectivity(&multi_adjacency(graph));
+
+    let components: Vec<Vec<(String, String)>> = result.components
+        .into_iter()
+        .map(|edges| {
+            edges
+                .into_iter()
+                .map(|(u, v)| (labels[u].clone(), labels[v].clone()))
+                .collect()
+        })
+        .collect();
+
+    return components;
+}
@@ -0,0 +1,412 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, position_of, predecessors, successors, weighted_successors};
+use crate::algorithms::paths::{edge_length, WeightFn, WeightInterpretation};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleError {
+    pub nodes: Vec<String>
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Graph contains a cycle through nodes: {}", self.nodes.join(", "))
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/*
+ * Iterative Tarjan. Only nodes flagged in 'allowed' are visited, so the
+ * same function works for the shrinking subgraphs used by Johnson's algorithm.
+ */
+pub fn strongly_connected_components(successors: &Vec<Vec<usize>>, allowed: &Vec<bool>) -> Vec<Vec<usize>> {
+    let size = successors.len();
+    let mut index: Vec<usize> = vec![usize::MAX; size];
+    let mut lowlink: Vec<usize> = vec![0; size];
+    let mut on_stack: Vec<bool> = vec![false; size];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut next_index: usize = 0;
+    let mut components: Vec<Vec<usize>> = Vec::new();
+
+    for root in 0..size {
+        if !allowed[root] || index[root] != usize::MAX {
+            continue;
+        }
+
+        let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&(v, i)) = call_stack.last() {
+            if i < successors[v].len() {
+                call_stack.last_mut().unwrap().1 += 1;
+                let w = successors[v][i];
+
+                if !allowed[w] {
+                    continue;
+                }
+
+                if index[w] == usize::MAX {
+                    index[w] = next_index;
+                    lowlink[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                }
+                else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+
+                continue;
+            }
+
+            call_stack.pop();
+
+            if let Some(&(u, _)) = call_stack.last() {
+                lowlink[u] = lowlink[u].min(lowlink[v]);
+            }
+
+            if lowlink[v] == index[v] {
+                let mut component: Vec<usize> = Vec::new();
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    return components;
+}
+
+fn nodes_on_cycles(successors: &Vec<Vec<usize>>) -> Vec<usize> {
+    let allowed = vec![true; successors.len()];
+    let mut on_cycle: Vec<usize> = Vec::new();
+
+    for component in strongly_connected_components(successors, &allowed) {
+        if component.len() > 1 || successors[component[0]].contains(&component[0]) {
+            on_cycle.extend(component);
+        }
+    }
+
+    on_cycle.sort();
+
+    return on_cycle;
+}
+
+/*
+ * Kahn's algorithm, processing ready nodes in insertion order so the
+ * result is deterministic.
+ */
+fn topological_order(successors: &Vec<Vec<usize>>) -> Option<Vec<usize>> {
+    let size = successors.len();
+    let mut in_degree: Vec<usize> = vec![0; size];
+
+    for adj in successors {
+        for j in adj {
+            in_degree[*j] += 1;
+        }
+    }
+
+    let mut ready: VecDeque<usize> = (0..size).filter(|i| in_degree[*i] == 0).collect();
+    let mut order: Vec<usize> = Vec::new();
+
+    while let Some(i) = ready.pop_front() {
+        order.push(i);
+
+        for j in &successors[i] {
+            in_degree[*j] -= 1;
+            if in_degree[*j] == 0 {
+                ready.push_back(*j);
+            }
+        }
+    }
+
+    if order.len() < size {
+        return None;
+    }
+
+    return Some(order);
+}
+
+pub fn topological_sort_indices(graph: &_Graph) -> Result<Vec<usize>, CycleError> {
+    let succ = successors(graph);
+
+    match topological_order(&succ) {
+        Some(order) => return Ok(order),
+        None => {
+            let labels = node_labels(graph);
+            let nodes = nodes_on_cycles(&succ)
+                .into_iter()
+                .map(|i| labels[i].clone())
+                .collect();
+
+            return Err(CycleError { nodes: nodes });
+        }
+    }
+}
+
+pub fn is_dag(graph: &_Graph) -> bool {
+    return topological_order(&successors(graph)).is_some();
+}
+
+pub fn topological_sort(graph: &_Graph) -> Result<Vec<String>, CycleError> {
+    let labels = node_labels(graph);
+    let order = topological_sort_indices(graph)?;
+
+    return Ok(order.into_iter().map(|i| labels[i].clone()).collect());
+}
+
+pub fn find_cycle(graph: &_Graph) -> Option<Vec<String>> {
+    // 0 = not visited, 1 = on the current path, 2 = finished
+    let succ = successors(graph);
+    let labels = node_labels(graph);
+    let size = succ.len();
+    let mut state: Vec<u8> = vec![0; size];
+
+    for root in 0..size {
+        if state[root] != 0 {
+            continue;
+        }
+
+        let mut path: Vec<usize> = vec![root];
+        let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+        state[root] = 1;
+
+        while let Some(&(v, i)) = call_stack.last() {
+            if i < succ[v].len() {
+                call_stack.last_mut().unwrap().1 += 1;
+                let w = succ[v][i];
+
+                if state[w] == 1 {
+                    let start = path.iter().position(|x| *x == w).unwrap();
+                    return Some(path[start..].iter().map(|x| labels[*x].clone()).collect());
+                }
+
+                if state[w] == 0 {
+                    state[w] = 1;
+                    path.push(w);
+                    call_stack.push((w, 0));
+                }
+
+                continue;
+            }
+
+            state[v] = 2;
+            path.pop();
+            call_stack.pop();
+        }
+    }
+
+    return None;
+}
+
+fn unblock(node: usize, blocked: &mut Vec<bool>, blocked_map: &mut Vec<HashSet<usize>>) {
+    let mut stack: Vec<usize> = vec![node];
+
+    while let Some(v) = stack.pop() {
+        if blocked[v] {
+            blocked[v] = false;
+            for w in blocked_map[v].drain() {
+                stack.push(w);
+            }
+        }
+    }
+}
+
+/*
+ * Johnson's algorithm: each strongly connected component is searched from
+ * one of its nodes, which is then removed before the remaining part of the
+ * component is split again.
+ */
+pub fn simple_cycles(graph: &_Graph) -> Vec<Vec<String>> {
+    let succ = successors(graph);
+    let labels = node_labels(graph);
+    let size = succ.len();
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+
+    for i in 0..size {
+        if succ[i].contains(&i) {
+            cycles.push(vec![i]);
+        }
+    }
+
+    let all_nodes = vec![true; size];
+    let mut components: Vec<Vec<usize>> = strongly_connected_components(&succ, &all_nodes)
+        .into_iter()
+        .filter(|c| c.len() > 1)
+        .collect();
+
+    while let Some(mut component) = components.pop() {
+        component.sort();
+        let start_node = component.remove(0);
+
+        let mut in_component: Vec<bool> = vec![false; size];
+        in_component[start_node] = true;
+        for v in &component {
+            in_component[*v] = true;
+        }
+
+        let neighbours = |v: usize| -> Vec<usize> {
+            return succ[v].iter().copied().filter(|w| in_component[*w] && *w != v).collect();
+        };
+
+        let mut path: Vec<usize> = vec![start_node];
+        let mut blocked: Vec<bool> = vec![false; size];
+        let mut closed: HashSet<usize> = HashSet::new();
+        let mut blocked_map: Vec<HashSet<usize>> = vec![HashSet::new(); size];
+        let mut stack: Vec<(usize, Vec<usize>)> = vec![(start_node, neighbours(start_node))];
+        blocked[start_node] = true;
+
+        while !stack.is_empty() {
+            let top = stack.len() - 1;
+            let this_node = stack[top].0;
+
+            if let Some(next_node) = stack[top].1.pop() {
+                if next_node == start_node {
+                    cycles.push(path.clone());
+                    closed.extend(path.iter().copied());
+                }
+                else if !blocked[next_node] {
+                    path.push(next_node);
+                    stack.push((next_node, neighbours(next_node)));
+                    closed.remove(&next_node);
+                    blocked[next_node] = true;
+                    continue;
+                }
+            }
+
+            if stack[top].1.is_empty() {
+                if closed.contains(&this_node) {
+                    unblock(this_node, &mut blocked, &mut blocked_map);
+                }
+                else {
+                    for w in neighbours(this_node) {
+                        blocked_map[w].insert(this_node);
+                    }
+                }
+
+                stack.pop();
+                path.pop();
+            }
+        }
+
+        let mut remaining: Vec<bool> = vec![false; size];
+        for v in &component {
+            remaining[*v] = true;
+        }
+
+        for sub_component in strongly_connected_components(&succ, &remaining) {
+            if sub_component.len() > 1 {
+                components.push(sub_component);
+            }
+        }
+    }
+
+    let named_cycles: Vec<Vec<String>> = cycles
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|i| labels[i].clone()).collect())
+        .collect();
+
+    return named_cycles;
+}
+
+/*
+ * Path with the largest total length, connection weights being turned into
+ * lengths as in the shortest path algorithms. With Unweighted every
+ * connection counts as 1, so the result is the path with the most edges.
+ * Connections without a finite length are ignored.
+ */
+pub fn dag_longest_path(
+    graph: &_Graph,
+    interpretation: &WeightInterpretation,
+    weight_fn: Option<WeightFn>
+) -> Result<Vec<String>, CycleError> {
+    let order = topological_sort_indices(graph)?;
+    let labels = node_labels(graph);
+    let succ = weighted_successors(graph);
+    let size = succ.len();
+
+    let mut length: Vec<f64> = vec![0.; size];
+    let mut previous: Vec<Option<usize>> = vec![None; size];
+
+    for u in order {
+        for (v, weight) in &succ[u] {
+            let step = edge_length(*weight, interpretation, weight_fn);
+            if !step.is_finite() {
+                continue;
+            }
+            if length[u] + step > length[*v] {
+                length[*v] = length[u] + step;
+                previous[*v] = Some(u);
+            }
+        }
+    }
+
+    let mut end: Option<usize> = None;
+    for i in 0..size {
+        if end.is_none() || length[i] > length[end.unwrap()] {
+            end = Some(i);
+        }
+    }
+
+    let mut path: Vec<String> = Vec::new();
+    let mut current = end;
+    while let Some(i) = current {
+        path.push(labels[i].clone());
+        current = previous[i];
+    }
+    path.reverse();
+
+    return Ok(path);
+}
+
+fn reachable(adjacency: &Vec<Vec<usize>>, start: usize) -> Vec<usize> {
+    let mut visited: Vec<bool> = vec![false; adjacency.len()];
+    let mut stack: Vec<usize> = vec![start];
+    let mut found: Vec<usize> = Vec::new();
+
+    while let Some(v) = stack.pop() {
+        for w in &adjacency[v] {
+            if !visited[*w] {
+                visited[*w] = true;
+                found.push(*w);
+                stack.push(*w);
+            }
+        }
+    }
+
+    return found;
+}
+
+pub fn descendants(graph: &_Graph, node_label: &str) -> HashSet<String> {
+    let labels = node_labels(graph);
+    let start = position_of(graph, node_label);
+
+    return reachable(&successors(graph), start)
+        .into_iter()
+        .filter(|i| *i != start)
+        .map(|i| labels[i].clone())
+        .collect();
+}
+
+pub fn ancestors(graph: &_Graph, node_label: &str) -> HashSet<String> {
+    let labels = node_labels(graph);
+    let start = position_of(graph, node_label);
+
+    return reachable(&predecessors(&successors(graph)), start)
+        .into_iter()
+        .filter(|i| *i != start)
+        .map(|i| labels[i].clone())
+        .collect();
+}
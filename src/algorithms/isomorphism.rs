@@ -0,0 +1,414 @@
+use std::collections::{HashMap, VecDeque};
+use crate::_Graph;
+use crate::_Node;
+use crate::graph_core::node::Connection;
+use crate::algorithms::adjacency::label_positions;
+
+pub type NodeMatch<'a> = &'a dyn Fn(&_Node, &_Node) -> bool;
+pub type EdgeMatch<'a> = &'a dyn Fn(&Connection, &Connection) -> bool;
+
+/*
+ * Copy of a node with its connections, detached from the graph.
+ */
+fn detached_copy(node: &_Node) -> _Node {
+    let connections: Vec<Connection> = node.connections
+        .iter()
+        .map(|conn| Connection {
+            node: conn.node.clone(),
+            weight: conn.weight,
+            directed: conn.directed,
+            time: conn.time
+        })
+        .collect();
+
+    return _Node {
+        connections: connections,
+        label: node.label.clone(),
+        x: node.x,
+        y: node.y,
+        index: node.index
+    };
+}
+
+/*
+ * Directed view used for matching: directed connections go one way,
+ * undirected ones both ways. Each (u, v) keeps where its connection is
+ * stored (owner node, index in owner.connections) so the callbacks and the
+ * 'directed' flag can be compared.
+ */
+struct MatchGraph {
+    nodes: Vec<_Node>,
+    out_adj: Vec<Vec<usize>>,
+    in_adj: Vec<Vec<usize>>,
+    edges: HashMap<(usize, usize), (usize, usize)>
+}
+
+impl MatchGraph {
+    fn new(graph: &_Graph) -> Self {
+        let positions = label_positions(graph);
+        let size = graph.nodes.len();
+        let mut out_adj: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut in_adj: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut edges: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+        for (i, n) in graph.nodes.iter().enumerate() {
+            let node = n.borrow();
+
+            for (k, conn) in node.connections.iter().enumerate() {
+                if let Some(rc_node) = conn.node.upgrade() {
+                    let j = positions[&rc_node.borrow().label];
+
+                    let mut pairs = vec![(i, j)];
+                    if !conn.directed && i != j {
+                        pairs.push((j, i));
+                    }
+
+                    for (u, v) in pairs {
+                        if !edges.contains_key(&(u, v)) {
+                            edges.insert((u, v), (i, k));
+                            out_adj[u].push(v);
+                            in_adj[v].push(u);
+                        }
+                    }
+                }
+            }
+        }
+
+        let nodes: Vec<_Node> = graph.nodes.iter().map(|n| detached_copy(&n.borrow())).collect();
+
+        return MatchGraph { nodes: nodes, out_adj: out_adj, in_adj: in_adj, edges: edges };
+    }
+
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        return self.edges.contains_key(&(u, v));
+    }
+}
+
+struct Matcher<'a> {
+    g1: &'a MatchGraph,
+    g2: &'a MatchGraph,
+    order: Vec<usize>,
+    core_1: Vec<Option<usize>>,
+    core_2: Vec<Option<usize>>,
+    induced: bool,
+    isomorphism: bool,
+    node_match: Option<NodeMatch<'a>>,
+    edge_match: Option<EdgeMatch<'a>>,
+    stop_at_first: bool,
+    results: Vec<Vec<(usize, usize)>>
+}
+
+impl<'a> Matcher<'a> {
+    fn edges_compatible(&self, t_edge: (usize, usize), p_edge: (usize, usize)) -> bool {
+        let (t_owner, t_index) = self.g1.edges[&t_edge];
+        let (p_owner, p_index) = self.g2.edges[&p_edge];
+
+        let t_conn = &self.g1.nodes[t_owner].connections[t_index];
+        let p_conn = &self.g2.nodes[p_owner].connections[p_index];
+
+        if t_conn.directed != p_conn.directed {
+            return false;
+        }
+
+        return match self.edge_match {
+            Some(f) => f(t_conn, p_conn),
+            None => true,
+        };
+    }
+
+    /*
+     * VF2 feasibility rules for adding (p -> t): every connection between p
+     * and the already mapped pattern nodes must exist between t and their
+     * images (and the other way round for induced matching), and t must have
+     * at least as many unmapped neighbours as p (look-ahead).
+     */
+    fn feasible(&self, p: usize, t: usize) -> bool {
+        let p_out = self.g2.out_adj[p].len();
+        let p_in = self.g2.in_adj[p].len();
+        let t_out = self.g1.out_adj[t].len();
+        let t_in = self.g1.in_adj[t].len();
+
+        if self.isomorphism && (p_out != t_out || p_in != t_in) {
+            return false;
+        }
+
+        if t_out < p_out || t_in < p_in {
+            return false;
+        }
+
+        if let Some(f) = self.node_match {
+            if !f(&self.g1.nodes[t], &self.g2.nodes[p]) {
+                return false;
+            }
+        }
+
+        if self.g2.has_edge(p, p) != self.g1.has_edge(t, t) && (self.induced || self.g2.has_edge(p, p)) {
+            return false;
+        }
+
+        if self.g2.has_edge(p, p) && !self.edges_compatible((t, t), (p, p)) {
+            return false;
+        }
+
+        let mut p_unmapped = 0;
+        for q in self.g2.out_adj[p].iter().chain(self.g2.in_adj[p].iter()) {
+            if *q == p {
+                continue;
+            }
+
+            match self.core_2[*q] {
+                Some(tq) => {
+                    if self.g2.has_edge(p, *q) && !(self.g1.has_edge(t, tq) && self.edges_compatible((t, tq), (p, *q))) {
+                        return false;
+                    }
+                    if self.g2.has_edge(*q, p) && !(self.g1.has_edge(tq, t) && self.edges_compatible((tq, t), (*q, p))) {
+                        return false;
+                    }
+                },
+                None => p_unmapped += 1
+            }
+        }
+
+        let mut t_unmapped = 0;
+        for tq in self.g1.out_adj[t].iter().chain(self.g1.in_adj[t].iter()) {
+            if *tq == t {
+                continue;
+            }
+
+            match self.core_1[*tq] {
+                Some(q) => {
+                    if self.induced && self.g1.has_edge(t, *tq) && !self.g2.has_edge(p, q) {
+                        return false;
+                    }
+                    if self.induced && self.g1.has_edge(*tq, t) && !self.g2.has_edge(q, p) {
+                        return false;
+                    }
+                },
+                None => t_unmapped += 1
+            }
+        }
+
+        return t_unmapped >= p_unmapped;
+    }
+
+    fn candidates(&self, p: usize) -> Vec<usize> {
+        for q in &self.g2.in_adj[p] {
+            if let Some(tq) = self.core_2[*q] {
+                return self.g1.out_adj[tq].iter().copied().filter(|t| self.core_1[*t].is_none()).collect();
+            }
+        }
+
+        for q in &self.g2.out_adj[p] {
+            if let Some(tq) = self.core_2[*q] {
+                return self.g1.in_adj[tq].iter().copied().filter(|t| self.core_1[*t].is_none()).collect();
+            }
+        }
+
+        return (0..self.core_1.len()).filter(|t| self.core_1[*t].is_none()).collect();
+    }
+
+    fn record(&mut self) {
+        let mapping: Vec<(usize, usize)> = (0..self.core_2.len()).map(|p| (p, self.core_2[p].unwrap())).collect();
+        self.results.push(mapping);
+    }
+
+    /*
+     * Depth-first over the matching order with an explicit stack: one frame
+     * per mapped pattern node, holding its candidates and the next one to
+     * try. A frame undoes its last mapping before trying the next candidate.
+     */
+    fn search(&mut self) {
+        if self.order.is_empty() {
+            self.record();
+            return;
+        }
+
+        let mut stack: Vec<(Vec<usize>, usize)> = vec![(self.candidates(self.order[0]), 0)];
+
+        while !stack.is_empty() {
+            let depth = stack.len() - 1;
+            let p = self.order[depth];
+
+            if let Some(t) = self.core_2[p] {
+                self.core_2[p] = None;
+                self.core_1[t] = None;
+            }
+
+            let (candidates, next) = &mut stack[depth];
+            let mut chosen: Option<usize> = None;
+            while *next < candidates.len() {
+                let t = candidates[*next];
+                *next += 1;
+
+                if self.feasible(p, t) {
+                    chosen = Some(t);
+                    break;
+                }
+            }
+
+            let t = match chosen {
+                Some(t) => t,
+                None => {
+                    stack.pop();
+                    continue;
+                }
+            };
+
+            self.core_2[p] = Some(t);
+            self.core_1[t] = Some(p);
+
+            if depth + 1 == self.order.len() {
+                self.record();
+
+                if self.stop_at_first {
+                    return;
+                }
+            } else {
+                stack.push((self.candidates(self.order[depth + 1]), 0));
+            }
+        }
+    }
+}
+
+/*
+ * Pattern nodes are matched in BFS order starting from the node with the
+ * highest degree, so most of them have an already mapped neighbour that
+ * restricts their candidates.
+ */
+fn matching_order(g: &MatchGraph) -> Vec<usize> {
+    let size = g.out_adj.len();
+    let degree = |v: usize| g.out_adj[v].len() + g.in_adj[v].len();
+    let mut visited: Vec<bool> = vec![false; size];
+    let mut order: Vec<usize> = Vec::new();
+
+    let mut by_degree: Vec<usize> = (0..size).collect();
+    by_degree.sort_by(|a, b| degree(*b).cmp(&degree(*a)));
+
+    for root in by_degree {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        let mut q: VecDeque<usize> = VecDeque::from([root]);
+
+        while let Some(v) = q.pop_front() {
+            order.push(v);
+
+            let mut next: Vec<usize> = g.out_adj[v].iter().chain(g.in_adj[v].iter()).copied().filter(|w| !visited[*w]).collect();
+            next.sort_by(|a, b| degree(*b).cmp(&degree(*a)));
+            next.dedup();
+
+            for w in next {
+                if !visited[w] {
+                    visited[w] = true;
+                    q.push_back(w);
+                }
+            }
+        }
+    }
+
+    return order;
+}
+
+fn run_matcher<'a>(
+    g1: &'a MatchGraph,
+    g2: &'a MatchGraph,
+    induced: bool,
+    isomorphism: bool,
+    node_match: Option<NodeMatch<'a>>,
+    edge_match: Option<EdgeMatch<'a>>,
+    stop_at_first: bool
+) -> Vec<Vec<(usize, usize)>> {
+    let mut matcher = Matcher {
+        g1: g1,
+        g2: g2,
+        order: matching_order(g2),
+        core_1: vec![None; g1.out_adj.len()],
+        core_2: vec![None; g2.out_adj.len()],
+        induced: induced,
+        isomorphism: isomorphism,
+        node_match: node_match,
+        edge_match: edge_match,
+        stop_at_first: stop_at_first,
+        results: Vec::new()
+    };
+
+    matcher.search();
+
+    return matcher.results;
+}
+
+/*
+ * Both graphs copied for matching, so the search does not hold them. The
+ * callbacks of the Python bindings may change the graphs while it runs; the
+ * search goes on over the graphs as they were when it started.
+ */
+pub struct Isomorphism {
+    graph: MatchGraph,
+    other: MatchGraph
+}
+
+impl Isomorphism {
+    pub fn new(graph: &_Graph, other: &_Graph) -> Isomorphism {
+        return Isomorphism {
+            graph: MatchGraph::new(graph),
+            other: MatchGraph::new(other)
+        };
+    }
+
+    pub fn is_isomorphic(&self, node_match: Option<NodeMatch>, edge_match: Option<EdgeMatch>) -> bool {
+        if self.graph.nodes.len() != self.other.nodes.len() {
+            return false;
+        }
+
+        if self.graph.edges.len() != self.other.edges.len() {
+            return false;
+        }
+
+        return !run_matcher(&self.graph, &self.other, true, true, node_match, edge_match, true).is_empty();
+    }
+
+    /*
+     * Every mapping (pattern label -> graph label) of the other graph, the
+     * pattern, into the graph. With 'induced' the matched nodes cannot have
+     * extra connections between them; otherwise only the pattern connections
+     * are required.
+     */
+    pub fn subgraph_isomorphisms(
+        &self,
+        induced: bool,
+        node_match: Option<NodeMatch>,
+        edge_match: Option<EdgeMatch>
+    ) -> Vec<HashMap<String, String>> {
+        if self.other.nodes.len() > self.graph.nodes.len() {
+            return Vec::new();
+        }
+
+        let mappings: Vec<HashMap<String, String>> = run_matcher(&self.graph, &self.other, induced, false, node_match, edge_match, false)
+            .into_iter()
+            .map(|mapping| {
+                mapping
+                    .into_iter()
+                    .map(|(p, t)| (self.other.nodes[p].label.clone(), self.graph.nodes[t].label.clone()))
+                    .collect()
+            })
+            .collect();
+
+        return mappings;
+    }
+}
+
+pub fn is_isomorphic(graph: &_Graph, other: &_Graph, node_match: Option<NodeMatch>, edge_match: Option<EdgeMatch>) -> bool {
+    return Isomorphism::new(graph, other).is_isomorphic(node_match, edge_match);
+}
+
+pub fn subgraph_isomorphisms(
+    graph: &_Graph,
+    pattern: &_Graph,
+    induced: bool,
+    node_match: Option<NodeMatch>,
+    edge_match: Option<EdgeMatch>
+) -> Vec<HashMap<String, String>> {
+    return Isomorphism::new(graph, pattern).subgraph_isomorphisms(induced, node_match, edge_match);
+}
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::{label_positions, neighbours, node_labels};
+
+// Katz scores are computed with the truncated series sum(beta^l * paths of length l)
+const KATZ_MAX_PATH_LENGTH: usize = 10;
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum LinkPredictor {
+    CommonNeighbours,
+    Jaccard,
+    AdamicAdar,
+    ResourceAllocation,
+    PreferentialAttachment,
+    Katz
+}
+
+fn katz_row(adjacency: &Vec<Vec<usize>>, source: usize, beta: f64) -> Vec<f64> {
+    let size = adjacency.len();
+    let mut scores: Vec<f64> = vec![0.; size];
+    let mut walks: Vec<f64> = vec![0.; size];
+    walks[source] = 1.;
+    let mut factor = 1.;
+
+    for _ in 0..KATZ_MAX_PATH_LENGTH {
+        let mut next: Vec<f64> = vec![0.; size];
+        for v in 0..size {
+            if walks[v] == 0. {
+                continue;
+            }
+            for w in &adjacency[v] {
+                next[*w] += walks[v];
+            }
+        }
+
+        factor *= beta;
+        for v in 0..size {
+            scores[v] += factor * next[v];
+        }
+        walks = next;
+    }
+
+    return scores;
+}
+
+/*
+ * Scores pairs of nodes that are not connected yet, using the undirected
+ * view of the graph. Without candidates every non-adjacent pair is scored.
+ * The result is sorted from the most to the least likely link.
+ */
+pub fn link_prediction(
+    graph: &_Graph,
+    method: &LinkPredictor,
+    candidates: Option<&Vec<(String, String)>>,
+    top_k: Option<usize>,
+    beta: f64
+) -> Vec<(String, String, f64)> {
+    let labels = node_labels(graph);
+    let positions = label_positions(graph);
+    let adjacency = neighbours(graph);
+    let neighbour_sets: Vec<HashSet<usize>> = adjacency.iter().map(|adj| adj.iter().copied().collect()).collect();
+    let size = adjacency.len();
+
+    let pairs: Vec<(usize, usize)> = match candidates {
+        Some(list) => list
+            .iter()
+            .map(|(a, b)| (
+                *positions.get(a).expect("Node not found"),
+                *positions.get(b).expect("Node not found")
+            ))
+            .collect(),
+        None => {
+            let mut all_pairs: Vec<(usize, usize)> = Vec::new();
+            for u in 0..size {
+                for v in (u + 1)..size {
+                    if !neighbour_sets[u].contains(&v) {
+                        all_pairs.push((u, v));
+                    }
+                }
+            }
+            all_pairs
+        }
+    };
+
+    let mut katz_rows: Vec<Option<Vec<f64>>> = vec![None; size];
+    let mut scored: Vec<(usize, usize, f64)> = Vec::new();
+
+    for (u, v) in pairs {
+        let common = || neighbour_sets[u].intersection(&neighbour_sets[v]).copied();
+
+        let score = match method {
+            LinkPredictor::CommonNeighbours => common().count() as f64,
+            LinkPredictor::Jaccard => {
+                let union = neighbour_sets[u].union(&neighbour_sets[v]).count();
+                if union == 0 { 0. } else { common().count() as f64 / union as f64 }
+            },
+            LinkPredictor::AdamicAdar => common()
+                .map(|z| adjacency[z].len() as f64)
+                .filter(|d| *d > 1.)
+                .map(|d| 1. / d.ln())
+                .sum(),
+            LinkPredictor::ResourceAllocation => common().map(|z| 1. / adjacency[z].len() as f64).sum(),
+            LinkPredictor::PreferentialAttachment => (adjacency[u].len() * adjacency[v].len()) as f64,
+            LinkPredictor::Katz => {
+                if katz_rows[u].is_none() {
+                    katz_rows[u] = Some(katz_row(&adjacency, u, beta));
+                }
+                katz_rows[u].as_ref().unwrap()[v]
+            }
+        };
+
+        scored.push((u, v, score));
+    }
+
+    scored.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap().then((a.0, a.1).cmp(&(b.0, b.1))));
+
+    if let Some(k) = top_k {
+        scored.truncate(k);
+    }
+
+    let predictions: Vec<(String, String, f64)> = scored
+        .into_iter()
+        .map(|(u, v, score)| (labels[u].clone(), labels[v].clone(), score))
+        .collect();
+
+    return predictions;
+}
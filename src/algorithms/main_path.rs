@@ -0,0 +1,240 @@
+use std::collections::{HashMap, HashSet};
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, predecessors, successors};
+use crate::algorithms::dag::{topological_sort_indices, CycleError};
+use crate::algorithms::subgraph::edge_subgraph;
+use crate::graph_core::edges::EdgeRef;
+
+const TIE_TOLERANCE: f64 = 1e-9;
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum TraversalWeight {
+    Spc,
+    Splc,
+    Spnp
+}
+
+type EdgeWeights = HashMap<(usize, usize), f64>;
+
+/*
+ * Counts, for every node, the paths arriving from sources (or from any node)
+ * and the paths leaving to sinks (or to any node). The traversal weight of
+ * (u, v) is the product of what arrives at u and what leaves from v:
+ *  - SPC:  sources -> u, v -> sinks
+ *  - SPLC: any node -> u, v -> sinks
+ *  - SPNP: any node -> u, v -> any node
+ */
+fn compute_traversal_weights(graph: &_Graph, weight_type: &TraversalWeight) -> Result<(Vec<(usize, usize, f64)>, Vec<Vec<usize>>), CycleError> {
+    let order = topological_sort_indices(graph)?;
+    let succ = successors(graph);
+    let pred = predecessors(&succ);
+    let size = succ.len();
+
+    let mut from_sources: Vec<f64> = vec![0.; size];
+    let mut from_all: Vec<f64> = vec![0.; size];
+
+    for u in order.iter() {
+        if pred[*u].is_empty() {
+            from_sources[*u] = 1.;
+        }
+        from_all[*u] += 1.;
+
+        for p in &pred[*u] {
+            from_sources[*u] += from_sources[*p];
+            from_all[*u] += from_all[*p];
+        }
+    }
+
+    let mut to_sinks: Vec<f64> = vec![0.; size];
+    let mut to_all: Vec<f64> = vec![0.; size];
+
+    for v in order.iter().rev() {
+        if succ[*v].is_empty() {
+            to_sinks[*v] = 1.;
+        }
+        to_all[*v] += 1.;
+
+        for w in &succ[*v] {
+            to_sinks[*v] += to_sinks[*w];
+            to_all[*v] += to_all[*w];
+        }
+    }
+
+    let mut weights: Vec<(usize, usize, f64)> = Vec::new();
+
+    for u in 0..size {
+        for v in &succ[u] {
+            let value = match weight_type {
+                TraversalWeight::Spc => from_sources[u] * to_sinks[*v],
+                TraversalWeight::Splc => from_all[u] * to_sinks[*v],
+                TraversalWeight::Spnp => from_all[u] * to_all[*v],
+            };
+            weights.push((u, *v, value));
+        }
+    }
+
+    return Ok((weights, succ));
+}
+
+pub fn traversal_weights(graph: &_Graph, weight_type: &TraversalWeight) -> Result<Vec<(String, String, f64)>, CycleError> {
+    let labels = node_labels(graph);
+    let (weights, _) = compute_traversal_weights(graph, weight_type)?;
+
+    let named_weights: Vec<(String, String, f64)> = weights
+        .into_iter()
+        .map(|(u, v, w)| (labels[u].clone(), labels[v].clone(), w))
+        .collect();
+
+    return Ok(named_weights);
+}
+
+fn is_tie(a: f64, b: f64) -> bool {
+    return (a - b).abs() <= TIE_TOLERANCE * a.abs().max(b.abs()).max(1.);
+}
+
+/*
+ * Greedy search used by the local and key-route main paths: from each node
+ * in the frontier, keep following the heaviest edge(s) until there is nowhere
+ * else to go. Ties are all followed.
+ */
+fn greedy_search(
+    start: Vec<usize>,
+    adjacency: &Vec<Vec<usize>>,
+    weight_of: &dyn Fn(usize, usize) -> f64,
+    selected: &mut HashSet<(usize, usize)>,
+    forward: bool
+) {
+    let mut frontier: Vec<usize> = start;
+    let mut visited: HashSet<usize> = frontier.iter().copied().collect();
+
+    while let Some(node) = frontier.pop() {
+        let mut best: f64 = f64::NEG_INFINITY;
+        for next in &adjacency[node] {
+            best = best.max(weight_of(node, *next));
+        }
+
+        for next in &adjacency[node] {
+            if !is_tie(weight_of(node, *next), best) {
+                continue;
+            }
+
+            if forward {
+                selected.insert((node, *next));
+            } else {
+                selected.insert((*next, node));
+            }
+
+            if visited.insert(*next) {
+                frontier.push(*next);
+            }
+        }
+    }
+}
+
+fn selected_subgraph(graph: &_Graph, selected: HashSet<(usize, usize)>, weights: &EdgeWeights) -> _Graph {
+    let mut times: HashMap<(usize, usize), Option<(f64, f64)>> = HashMap::new();
+    for edge in graph.edges() {
+        times.entry((edge.from, edge.to)).or_insert(edge.time);
+    }
+
+    let mut edges: Vec<EdgeRef> = selected
+        .into_iter()
+        .map(|(u, v)| EdgeRef { from: u, to: v, weight: weights[&(u, v)] as f32, directed: true, time: times.get(&(u, v)).copied().flatten() })
+        .collect();
+    edges.sort_by(|a, b| (a.from, a.to).cmp(&(b.from, b.to)));
+
+    return edge_subgraph(graph, &edges);
+}
+
+pub fn local_main_path(graph: &_Graph, weight_type: &TraversalWeight) -> Result<_Graph, CycleError> {
+    let (weight_list, succ) = compute_traversal_weights(graph, weight_type)?;
+    let pred = predecessors(&succ);
+    let weights: EdgeWeights = weight_list.iter().map(|(u, v, w)| ((*u, *v), *w)).collect();
+
+    let mut best_start: f64 = f64::NEG_INFINITY;
+    for (u, _, w) in &weight_list {
+        if pred[*u].is_empty() {
+            best_start = best_start.max(*w);
+        }
+    }
+
+    let mut selected: HashSet<(usize, usize)> = HashSet::new();
+    let mut start: Vec<usize> = Vec::new();
+
+    for (u, v, w) in &weight_list {
+        if pred[*u].is_empty() && is_tie(*w, best_start) {
+            selected.insert((*u, *v));
+            start.push(*v);
+        }
+    }
+
+    let weight_of = |u: usize, v: usize| weights[&(u, v)];
+    greedy_search(start, &succ, &weight_of, &mut selected, true);
+
+    return Ok(selected_subgraph(graph, selected, &weights));
+}
+
+pub fn global_main_path(graph: &_Graph, weight_type: &TraversalWeight) -> Result<_Graph, CycleError> {
+    let order = topological_sort_indices(graph)?;
+    let (weight_list, succ) = compute_traversal_weights(graph, weight_type)?;
+    let weights: EdgeWeights = weight_list.iter().map(|(u, v, w)| ((*u, *v), *w)).collect();
+    let size = succ.len();
+
+    let mut total: Vec<f64> = vec![0.; size];
+    let mut previous: Vec<Option<usize>> = vec![None; size];
+
+    for u in order {
+        for v in &succ[u] {
+            let candidate = total[u] + weights[&(u, *v)];
+            if candidate > total[*v] {
+                total[*v] = candidate;
+                previous[*v] = Some(u);
+            }
+        }
+    }
+
+    let mut end: Option<usize> = None;
+    for i in 0..size {
+        if previous[i].is_some() && (end.is_none() || total[i] > total[end.unwrap()]) {
+            end = Some(i);
+        }
+    }
+
+    let mut selected: HashSet<(usize, usize)> = HashSet::new();
+    let mut current = end;
+    while let Some(v) = current {
+        if let Some(u) = previous[v] {
+            selected.insert((u, v));
+        }
+        current = previous[v];
+    }
+
+    return Ok(selected_subgraph(graph, selected, &weights));
+}
+
+/*
+ * The key routes are the 'key_routes' heaviest edges. Each one is extended
+ * forward to a sink and backward to a source with the same greedy search
+ * used by the local main path.
+ */
+pub fn key_route_main_path(graph: &_Graph, key_routes: usize, weight_type: &TraversalWeight) -> Result<_Graph, CycleError> {
+    let (mut weight_list, succ) = compute_traversal_weights(graph, weight_type)?;
+    let pred = predecessors(&succ);
+    let weights: EdgeWeights = weight_list.iter().map(|(u, v, w)| ((*u, *v), *w)).collect();
+
+    weight_list.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+
+    let mut selected: HashSet<(usize, usize)> = HashSet::new();
+    let weight_of = |u: usize, v: usize| weights[&(u, v)];
+    let reverse_weight_of = |v: usize, u: usize| weights[&(u, v)];
+
+    for (u, v, _) in weight_list.iter().take(key_routes) {
+        selected.insert((*u, *v));
+        greedy_search(vec![*v], &succ, &weight_of, &mut selected, true);
+        greedy_search(vec![*u], &pred, &reverse_weight_of, &mut selected, false);
+    }
+
+    return Ok(selected_subgraph(graph, selected, &weights));
+}
@@ -0,0 +1,25 @@
+pub mod adjacency;
+pub mod dag;
+pub mod subgraph;
+pub mod main_path;
+pub mod bipartite;
+pub mod isomorphism;
+pub mod null_models;
+pub mod motifs;
+pub mod random_walks;
+pub mod link_prediction;
+pub mod similarity;
+pub mod connectivity;
+pub mod cliques;
+pub mod paths;
+pub mod centrality;
+pub mod robustness;
+pub mod rewiring;
+pub mod sampling;
+pub mod quotient;
+pub mod node_metrics;
+pub mod summary;
+pub mod power_law;
+pub mod backbone;
+pub mod traversal;
+pub mod temporal;
@@ -0,0 +1,270 @@
+use std::collections::{HashMap, HashSet};
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::neighbours;
+use crate::algorithms::null_models::{
+    arc_count, gnm_directed, gnm_undirected, rewired_directed, rewired_undirected, seeded_rng, NullModel
+};
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum MotifCensus {
+    Triads,
+    Size3,
+    Size4
+}
+
+pub const TRIAD_NAMES: [&str; 16] = [
+    "003", "012", "102", "021D", "021U", "021C", "111D", "111U",
+    "030T", "030C", "201", "120D", "120U", "120C", "210", "300"
+];
+
+// Triad type (1 based index in TRIAD_NAMES) for each of the 64 arc combinations
+const TRICODES: [usize; 64] = [
+    1, 2, 2, 3, 2, 4, 6, 8, 2, 6, 5, 7, 3, 8, 7, 11,
+    2, 6, 4, 8, 5, 9, 9, 13, 6, 10, 9, 14, 7, 14, 12, 15,
+    2, 5, 6, 7, 6, 9, 10, 14, 4, 9, 9, 12, 8, 13, 14, 15,
+    3, 7, 8, 11, 7, 12, 14, 15, 8, 14, 13, 15, 11, 15, 15, 16
+];
+
+pub const SIZE3_MOTIFS: [&str; 2] = ["open_triad", "triangle"];
+pub const SIZE4_MOTIFS: [&str; 6] = ["path", "star", "cycle", "tailed_triangle", "diamond", "clique"];
+
+/*
+ * Directed view used by the triad census: directed connections are arcs,
+ * undirected connections count as mutual arcs. Self-loops are ignored.
+ */
+fn directed_view(graph: &_Graph) -> Vec<HashSet<usize>> {
+    let mut out_adj: Vec<HashSet<usize>> = vec![HashSet::new(); graph.nodes.len()];
+
+    for edge in graph.edges() {
+        if edge.from == edge.to {
+            continue;
+        }
+
+        out_adj[edge.from].insert(edge.to);
+        if !edge.directed {
+            out_adj[edge.to].insert(edge.from);
+        }
+    }
+
+    return out_adj;
+}
+
+fn undirected_view(graph: &_Graph) -> Vec<HashSet<usize>> {
+    return neighbours(graph)
+        .into_iter()
+        .map(|adj| adj.into_iter().collect())
+        .collect();
+}
+
+/*
+ * Batagelj & Mrvar's subquadratic triad census: only pairs connected by at
+ * least one arc are visited, dyadic triads are counted with a formula and
+ * the empty ones (003) are whatever is left.
+ */
+fn triad_counts(out_adj: &Vec<HashSet<usize>>) -> Vec<f64> {
+    let size = out_adj.len();
+    let mut in_adj: Vec<HashSet<usize>> = vec![HashSet::new(); size];
+    for (u, adj) in out_adj.iter().enumerate() {
+        for v in adj {
+            in_adj[*v].insert(u);
+        }
+    }
+
+    let has_arc = |a: usize, b: usize| out_adj[a].contains(&b);
+    let tricode = |v: usize, u: usize, w: usize| -> usize {
+        let combos = [(v, u, 1), (u, v, 2), (v, w, 4), (w, v, 8), (u, w, 16), (w, u, 32)];
+        return combos.iter().filter(|(a, b, _)| has_arc(*a, *b)).map(|(_, _, x)| x).sum();
+    };
+
+    let mut census: Vec<f64> = vec![0.; 16];
+
+    for v in 0..size {
+        let v_neighbours: HashSet<usize> = out_adj[v].union(&in_adj[v]).copied().collect();
+
+        for u in v_neighbours.iter().copied() {
+            if u <= v {
+                continue;
+            }
+
+            let mut pair_neighbours: HashSet<usize> = v_neighbours.clone();
+            pair_neighbours.extend(out_adj[u].iter().copied());
+            pair_neighbours.extend(in_adj[u].iter().copied());
+            pair_neighbours.remove(&u);
+            pair_neighbours.remove(&v);
+
+            for w in pair_neighbours.iter().copied() {
+                if u < w || (v < w && w < u && !v_neighbours.contains(&w)) {
+                    census[TRICODES[tricode(v, u, w)] - 1] += 1.;
+                }
+            }
+
+            let dyadic = (size - pair_neighbours.len() - 2) as f64;
+            if has_arc(u, v) && has_arc(v, u) {
+                census[2] += dyadic;
+            } else {
+                census[1] += dyadic;
+            }
+        }
+    }
+
+    let n = size as f64;
+    let total_triads = n * (n - 1.) * (n - 2.) / 6.;
+    let counted: f64 = census.iter().sum();
+    census[0] = (total_triads - counted).max(0.);
+
+    return census;
+}
+
+fn classify_size4(nodes: &Vec<usize>, adj: &Vec<HashSet<usize>>) -> usize {
+    let degrees: Vec<usize> = nodes
+        .iter()
+        .map(|v| nodes.iter().filter(|w| adj[*v].contains(w)).count())
+        .collect();
+    let edges: usize = degrees.iter().sum::<usize>() / 2;
+    let max_degree = *degrees.iter().max().unwrap();
+
+    return match (edges, max_degree) {
+        (3, 3) => 1,
+        (3, _) => 0,
+        (4, 3) => 3,
+        (4, _) => 2,
+        (5, _) => 4,
+        _ => 5,
+    };
+}
+
+/*
+ * ESU enumeration of connected induced subgraphs with 'motif_size' nodes:
+ * each subgraph is grown from its smallest node, only through nodes larger
+ * than it that are not already adjacent to the current subgraph.
+ */
+fn undirected_motif_counts(adj: &Vec<HashSet<usize>>, motif_size: usize) -> Vec<f64> {
+    let mut counts: Vec<f64> = vec![0.; if motif_size == 3 { 2 } else { 6 }];
+
+    fn extend(
+        subgraph: &mut Vec<usize>,
+        extension: Vec<usize>,
+        root: usize,
+        motif_size: usize,
+        adj: &Vec<HashSet<usize>>,
+        counts: &mut Vec<f64>
+    ) {
+        if subgraph.len() == motif_size {
+            let index = if motif_size == 3 {
+                let edges: usize = subgraph.iter().map(|v| subgraph.iter().filter(|w| adj[*v].contains(w)).count()).sum::<usize>() / 2;
+                if edges == 3 { 1 } else { 0 }
+            } else {
+                classify_size4(subgraph, adj)
+            };
+            counts[index] += 1.;
+            return;
+        }
+
+        let mut extension = extension;
+        while let Some(w) = extension.pop() {
+            let mut next_extension = extension.clone();
+
+            for u in adj[w].iter().copied() {
+                if u <= root || subgraph.contains(&u) || next_extension.contains(&u) {
+                    continue;
+                }
+
+                if subgraph.iter().any(|s| adj[*s].contains(&u)) {
+                    continue;
+                }
+
+                next_extension.push(u);
+            }
+
+            subgraph.push(w);
+            extend(subgraph, next_extension, root, motif_size, adj, counts);
+            subgraph.pop();
+        }
+    }
+
+    for v in 0..adj.len() {
+        let extension: Vec<usize> = adj[v].iter().copied().filter(|u| *u > v).collect();
+        let mut subgraph: Vec<usize> = vec![v];
+        extend(&mut subgraph, extension, v, motif_size, adj, &mut counts);
+    }
+
+    return counts;
+}
+
+fn named_counts(names: &[&str], counts: Vec<f64>) -> HashMap<String, f64> {
+    return names.iter().zip(counts).map(|(name, count)| (name.to_string(), count)).collect();
+}
+
+pub fn triad_census(graph: &_Graph) -> HashMap<String, f64> {
+    return named_counts(&TRIAD_NAMES, triad_counts(&directed_view(graph)));
+}
+
+pub fn motif_census(graph: &_Graph, motif_size: usize) -> HashMap<String, f64> {
+    let adj = undirected_view(graph);
+
+    match motif_size {
+        3 => return named_counts(&SIZE3_MOTIFS, undirected_motif_counts(&adj, 3)),
+        4 => return named_counts(&SIZE4_MOTIFS, undirected_motif_counts(&adj, 4)),
+        _ => panic!("Motif size must be 3 or 4"),
+    }
+}
+
+/*
+ * z = (observed - mean) / standard deviation, where mean and deviation come
+ * from 'n_samples' random graphs of the null model. A type with no variation
+ * in the null model gets NaN.
+ */
+pub fn motif_z_scores(
+    graph: &_Graph,
+    census: &MotifCensus,
+    n_samples: usize,
+    null_model: &NullModel,
+    seed: Option<u64>
+) -> HashMap<String, f64> {
+    let mut rng = seeded_rng(seed);
+    let size = graph.nodes.len();
+
+    let view = match census {
+        MotifCensus::Triads => directed_view(graph),
+        _ => undirected_view(graph),
+    };
+
+    let (names, observed, count_fn): (&[&str], Vec<f64>, Box<dyn Fn(&Vec<HashSet<usize>>) -> Vec<f64>>) = match census {
+        MotifCensus::Triads => (&TRIAD_NAMES, triad_counts(&view), Box::new(triad_counts)),
+        MotifCensus::Size3 => (&SIZE3_MOTIFS, undirected_motif_counts(&view, 3), Box::new(|adj| undirected_motif_counts(adj, 3))),
+        MotifCensus::Size4 => (&SIZE4_MOTIFS, undirected_motif_counts(&view, 4), Box::new(|adj| undirected_motif_counts(adj, 4))),
+    };
+
+    let arcs = match census {
+        MotifCensus::Triads => arc_count(&view),
+        _ => arc_count(&view) / 2,
+    };
+
+    let mut samples: Vec<Vec<f64>> = Vec::new();
+
+    for _ in 0..n_samples {
+        let random_adj = match (null_model, census) {
+            (NullModel::Gnm, MotifCensus::Triads) => gnm_directed(size, arcs, &mut rng),
+            (NullModel::Gnm, _) => gnm_undirected(size, arcs, &mut rng),
+            (NullModel::DegreePreserving, MotifCensus::Triads) => rewired_directed(&view, &mut rng),
+            (NullModel::DegreePreserving, _) => rewired_undirected(&view, &mut rng),
+        };
+        samples.push(count_fn(&random_adj));
+    }
+
+    let mut z_scores: HashMap<String, f64> = HashMap::new();
+
+    for (k, name) in names.iter().enumerate() {
+        let values: Vec<f64> = samples.iter().map(|s| s[k]).collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        let std = variance.sqrt();
+
+        let z = if std > 0. { (observed[k] - mean) / std } else { f64::NAN };
+        z_scores.insert(name.to_string(), z);
+    }
+
+    return z_scores;
+}
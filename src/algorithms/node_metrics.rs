@@ -0,0 +1,202 @@
+use std::collections::VecDeque;
+use std::fmt;
+use crate::_Graph;
+use crate::algorithms::adjacency::{neighbours, node_labels};
+use crate::algorithms::centrality::betweenness;
+
+pub const NODE_METRICS: [&str; 6] = ["degree", "strength", "centrality", "clustering", "betweenness", "closeness"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownMetricError {
+    pub metric: String
+}
+
+impl fmt::Display for UnknownMetricError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown node metric '{}', expected one of: {}", self.metric, NODE_METRICS.join(", "))
+    }
+}
+
+impl std::error::Error for UnknownMetricError {}
+
+/*
+ * One entry per node in every column, in the order of graph.nodes. Columns
+ * of metrics that were not requested are None. Degrees, strengths and
+ * degree centralities follow compute_degrees, get_node_strength and
+ * get_centrality_degrees.
+ */
+#[derive(Clone, Debug, Default)]
+pub struct NodeMetrics {
+    pub label: Vec<String>,
+    pub in_degree: Option<Vec<i32>>,
+    pub out_degree: Option<Vec<i32>>,
+    pub total_degree: Option<Vec<i32>>,
+    pub undirected_degree: Option<Vec<i32>>,
+    pub in_strength: Option<Vec<f32>>,
+    pub out_strength: Option<Vec<f32>>,
+    pub total_strength: Option<Vec<f32>>,
+    pub in_centrality: Option<Vec<f32>>,
+    pub out_centrality: Option<Vec<f32>>,
+    pub total_centrality: Option<Vec<f32>>,
+    pub undirected_centrality: Option<Vec<f32>>,
+    pub clustering: Option<Vec<f64>>,
+    pub betweenness: Option<Vec<f64>>,
+    pub closeness: Option<Vec<f64>>
+}
+
+/*
+ * Local clustering coefficient of the undirected view: share of the pairs
+ * of neighbours that are connected, 0 for nodes with less than two.
+ */
+fn clustering(adjacency: &Vec<Vec<usize>>) -> Vec<f64> {
+    let mut marked: Vec<bool> = vec![false; adjacency.len()];
+    let mut coefficients: Vec<f64> = Vec::new();
+
+    for adj in adjacency {
+        let k = adj.len();
+        if k < 2 {
+            coefficients.push(0.);
+            continue;
+        }
+
+        for w in adj {
+            marked[*w] = true;
+        }
+
+        let mut links = 0;
+        for w in adj {
+            links += adjacency[*w].iter().filter(|x| marked[**x]).count();
+        }
+
+        for w in adj {
+            marked[*w] = false;
+        }
+
+        // Every link between two neighbours was seen from both ends
+        coefficients.push(links as f64 / (k * (k - 1)) as f64);
+    }
+
+    return coefficients;
+}
+
+/*
+ * Closeness of the undirected view: number of reachable nodes divided by
+ * the sum of the hops to them, 0 for nodes that reach nobody.
+ */
+fn closeness(adjacency: &Vec<Vec<usize>>) -> Vec<f64> {
+    let size = adjacency.len();
+    let mut scores: Vec<f64> = Vec::new();
+
+    for source in 0..size {
+        let mut distance: Vec<usize> = vec![usize::MAX; size];
+        distance[source] = 0;
+        let mut q: VecDeque<usize> = VecDeque::from([source]);
+        let mut reached = 0;
+        let mut total = 0;
+
+        while let Some(v) = q.pop_front() {
+            for w in &adjacency[v] {
+                if distance[*w] == usize::MAX {
+                    distance[*w] = distance[v] + 1;
+                    reached += 1;
+                    total += distance[*w];
+                    q.push_back(*w);
+                }
+            }
+        }
+
+        scores.push(if total == 0 { 0. } else { reached as f64 / total as f64 });
+    }
+
+    return scores;
+}
+
+/*
+ * Computes the requested metrics ("degree", "strength", "centrality",
+ * "clustering", "betweenness", "closeness") for every node. Degrees and
+ * strengths come from a single pass over the connections; the structural
+ * metrics share one undirected view of the graph.
+ */
+pub fn node_metrics(graph: &_Graph, metrics: &Vec<String>) -> Result<NodeMetrics, UnknownMetricError> {
+    for metric in metrics {
+        if !NODE_METRICS.contains(&metric.as_str()) {
+            return Err(UnknownMetricError { metric: metric.clone() });
+        }
+    }
+
+    let wants = |name: &str| metrics.iter().any(|m| m == name);
+    let size = graph.nodes.len();
+    let mut result = NodeMetrics { label: node_labels(graph), ..Default::default() };
+
+    if wants("degree") || wants("strength") || wants("centrality") {
+        let mut in_degree: Vec<i32> = vec![0; size];
+        let mut out_degree: Vec<i32> = vec![0; size];
+        let mut undirected_degree: Vec<i32> = vec![0; size];
+        let mut in_strength: Vec<f32> = vec![0.; size];
+        let mut out_strength: Vec<f32> = vec![0.; size];
+
+        for edge in graph.edges() {
+            let (from, to, weight) = (edge.from, edge.to, edge.weight);
+
+            if edge.directed {
+                out_degree[from] += 1;
+                in_degree[to] += 1;
+            } else {
+                undirected_degree[from] += 1;
+                if to != from {
+                    undirected_degree[to] += 1;
+                }
+                in_strength[from] += weight;
+                out_strength[to] += weight;
+            }
+
+            out_strength[from] += weight;
+            in_strength[to] += weight;
+        }
+
+        let total_degree: Vec<i32> = (0..size).map(|v| in_degree[v] + out_degree[v]).collect();
+
+        if wants("centrality") {
+            let normalize = |degrees: &Vec<i32>| -> Vec<f32> {
+                if size <= 1 {
+                    return vec![0.; size];
+                }
+                return degrees.iter().map(|d| *d as f32 / (size - 1) as f32).collect();
+            };
+
+            result.in_centrality = Some(normalize(&in_degree));
+            result.out_centrality = Some(normalize(&out_degree));
+            result.total_centrality = Some(normalize(&total_degree));
+            result.undirected_centrality = Some(normalize(&undirected_degree));
+        }
+
+        if wants("strength") {
+            result.total_strength = Some((0..size).map(|v| in_strength[v] + out_strength[v]).collect());
+            result.in_strength = Some(in_strength);
+            result.out_strength = Some(out_strength);
+        }
+
+        if wants("degree") {
+            result.in_degree = Some(in_degree);
+            result.out_degree = Some(out_degree);
+            result.total_degree = Some(total_degree);
+            result.undirected_degree = Some(undirected_degree);
+        }
+    }
+
+    if wants("clustering") || wants("betweenness") || wants("closeness") {
+        let adjacency = neighbours(graph);
+
+        if wants("clustering") {
+            result.clustering = Some(clustering(&adjacency));
+        }
+        if wants("betweenness") {
+            result.betweenness = Some(betweenness(&adjacency).0);
+        }
+        if wants("closeness") {
+            result.closeness = Some(closeness(&adjacency));
+        }
+    }
+
+    return Ok(result);
+}
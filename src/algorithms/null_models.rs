@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum NullModel {
+    Gnm,
+    DegreePreserving
+}
+
+// Swaps attempted per edge by default, and attempts allowed per requested swap
+pub const SWAPS_PER_EDGE: usize = 10;
+const ATTEMPTS_PER_SWAP: usize = 100;
+
+pub fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(s) => return StdRng::seed_from_u64(s),
+        None => return StdRng::from_entropy(),
+    }
+}
+
+pub fn arc_count(out_adj: &Vec<HashSet<usize>>) -> usize {
+    return out_adj.iter().map(|adj| adj.len()).sum();
+}
+
+/*
+ * Random graph with 'size' nodes and 'arcs' distinct arcs (no self-loops),
+ * returned as out-neighbour sets.
+ */
+pub fn gnm_directed(size: usize, arcs: usize, rng: &mut StdRng) -> Vec<HashSet<usize>> {
+    let mut out_adj: Vec<HashSet<usize>> = vec![HashSet::new(); size];
+    let max_arcs = size * size.saturating_sub(1);
+    let mut placed = 0;
+
+    while placed < arcs.min(max_arcs) {
+        let u = rng.gen_range(0..size);
+        let v = rng.gen_range(0..size);
+
+        if u != v && out_adj[u].insert(v) {
+            placed += 1;
+        }
+    }
+
+    return out_adj;
+}
+
+/*
+ * Same as gnm_directed, but every edge is stored in both directions.
+ */
+pub fn gnm_undirected(size: usize, edges: usize, rng: &mut StdRng) -> Vec<HashSet<usize>> {
+    let mut adj: Vec<HashSet<usize>> = vec![HashSet::new(); size];
+    let max_edges = size * size.saturating_sub(1) / 2;
+    let mut placed = 0;
+
+    while placed < edges.min(max_edges) {
+        let u = rng.gen_range(0..size);
+        let v = rng.gen_range(0..size);
+
+        if u != v && adj[u].insert(v) {
+            adj[v].insert(u);
+            placed += 1;
+        }
+    }
+
+    return adj;
+}
+
+/*
+ * Double edge swap: picks two edges (a, b) and (c, d) and replaces them with
+ * (a, d) and (c, b), or (a, c) and (b, d), keeping every degree. Swaps that
+ * would create a self-loop or a repeated edge are rejected. 'edges' is
+ * updated in place; returns the number of swaps done.
+ */
+pub fn double_edge_swap(edges: &mut Vec<(usize, usize)>, n_swaps: usize, rng: &mut StdRng) -> usize {
+    let key = |a: usize, b: usize| (a.min(b), a.max(b));
+    let mut existing: HashSet<(usize, usize)> = edges.iter().map(|(a, b)| key(*a, *b)).collect();
+    let mut done = 0;
+
+    if edges.len() < 2 {
+        return 0;
+    }
+
+    for _ in 0..(n_swaps * ATTEMPTS_PER_SWAP) {
+        if done == n_swaps {
+            break;
+        }
+
+        let i = rng.gen_range(0..edges.len());
+        let j = rng.gen_range(0..edges.len());
+        if i == j {
+            continue;
+        }
+
+        let (a, b) = edges[i];
+        let (c, d) = if rng.gen_bool(0.5) { edges[j] } else { (edges[j].1, edges[j].0) };
+
+        if a == d || c == b || existing.contains(&key(a, d)) || existing.contains(&key(c, b)) {
+            continue;
+        }
+
+        existing.remove(&key(a, b));
+        existing.remove(&key(c, d));
+        existing.insert(key(a, d));
+        existing.insert(key(c, b));
+        edges[i] = (a, d);
+        edges[j] = (c, b);
+        done += 1;
+    }
+
+    return done;
+}
+
+/*
+ * Directed version of double_edge_swap: (a -> b), (c -> d) become
+ * (a -> d), (c -> b), which keeps every in-degree and out-degree.
+ */
+pub fn directed_edge_swap(arcs: &mut Vec<(usize, usize)>, n_swaps: usize, rng: &mut StdRng) -> usize {
+    let mut existing: HashSet<(usize, usize)> = arcs.iter().copied().collect();
+    let mut done = 0;
+
+    if arcs.len() < 2 {
+        return 0;
+    }
+
+    for _ in 0..(n_swaps * ATTEMPTS_PER_SWAP) {
+        if done == n_swaps {
+            break;
+        }
+
+        let i = rng.gen_range(0..arcs.len());
+        let j = rng.gen_range(0..arcs.len());
+        if i == j {
+            continue;
+        }
+
+        let (a, b) = arcs[i];
+        let (c, d) = arcs[j];
+
+        if a == d || c == b || existing.contains(&(a, d)) || existing.contains(&(c, b)) {
+            continue;
+        }
+
+        existing.remove(&(a, b));
+        existing.remove(&(c, d));
+        existing.insert((a, d));
+        existing.insert((c, b));
+        arcs[i] = (a, d);
+        arcs[j] = (c, b);
+        done += 1;
+    }
+
+    return done;
+}
+
+/*
+ * Degree preserving versions of gnm_directed and gnm_undirected: the arcs of
+ * the given adjacency are rewired with SWAPS_PER_EDGE swaps per arc.
+ */
+pub fn rewired_directed(out_adj: &Vec<HashSet<usize>>, rng: &mut StdRng) -> Vec<HashSet<usize>> {
+    let mut arcs: Vec<(usize, usize)> = Vec::new();
+    for (u, adj) in out_adj.iter().enumerate() {
+        let mut targets: Vec<usize> = adj.iter().copied().collect();
+        targets.sort();
+        arcs.extend(targets.into_iter().map(|v| (u, v)));
+    }
+
+    let n_swaps = arcs.len() * SWAPS_PER_EDGE;
+    directed_edge_swap(&mut arcs, n_swaps, rng);
+
+    let mut rewired: Vec<HashSet<usize>> = vec![HashSet::new(); out_adj.len()];
+    for (u, v) in arcs {
+        rewired[u].insert(v);
+    }
+
+    return rewired;
+}
+
+pub fn rewired_undirected(adj: &Vec<HashSet<usize>>, rng: &mut StdRng) -> Vec<HashSet<usize>> {
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for (u, neighbours) in adj.iter().enumerate() {
+        let mut targets: Vec<usize> = neighbours.iter().copied().filter(|v| u < *v).collect();
+        targets.sort();
+        edges.extend(targets.into_iter().map(|v| (u, v)));
+    }
+
+    let n_swaps = edges.len() * SWAPS_PER_EDGE;
+    double_edge_swap(&mut edges, n_swaps, rng);
+
+    let mut rewired: Vec<HashSet<usize>> = vec![HashSet::new(); adj.len()];
+    for (u, v) in edges {
+        rewired[u].insert(v);
+        rewired[v].insert(u);
+    }
+
+    return rewired;
+}
@@ -0,0 +1,386 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, position_of, weighted_adjacency};
+
+/*
+ * How Connection.weight becomes the length of a connection in path
+ * algorithms:
+ *  - Distance:   the weight itself
+ *  - Strength:   1 / weight, so strong ties are short (co-occurrence counts)
+ *  - Log:        1 / ln(1 + weight), a damped version of Strength
+ *  - Unweighted: every connection has length 1
+ */
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum WeightInterpretation {
+    Distance,
+    Strength,
+    Log,
+    Unweighted
+}
+
+// Custom weight -> length conversion, used instead of the interpretation when given
+pub type WeightFn<'a> = &'a dyn Fn(f32) -> f64;
+
+pub fn edge_length(weight: f32, interpretation: &WeightInterpretation, weight_fn: Option<WeightFn>) -> f64 {
+    if let Some(f) = weight_fn {
+        return f(weight);
+    }
+
+    let weight = weight as f64;
+    match interpretation {
+        WeightInterpretation::Distance => return weight,
+        WeightInterpretation::Strength => return 1. / weight,
+        WeightInterpretation::Log => return 1. / weight.ln_1p(),
+        WeightInterpretation::Unweighted => return 1.,
+    }
+}
+
+/*
+ * A connection whose weight turned into a negative or NaN length, which
+ * shortest paths cannot handle.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidLengthError {
+    pub from: String,
+    pub to: String,
+    pub length: f64
+}
+
+impl fmt::Display for InvalidLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Connection from {} to {} has length {}; lengths must not be negative or NaN", self.from, self.to, self.length)
+    }
+}
+
+impl std::error::Error for InvalidLengthError {}
+
+pub fn check_length(length: f64, from: &str, to: &str) -> Result<(), InvalidLengthError> {
+    if length.is_nan() || length < 0. {
+        return Err(InvalidLengthError { from: from.to_string(), to: to.to_string(), length: length });
+    }
+
+    return Ok(());
+}
+
+/*
+ * Weighted adjacency lists shared by the path algorithms: directed
+ * connections go one way and undirected connections both ways, as in
+ * generate_adjacency_matrix. Weights are turned into lengths, negative or
+ * NaN lengths are an error, connections with an infinite length are left
+ * out, parallel connections are merged keeping the shortest one, and
+ * neighbours are sorted by index.
+ */
+pub struct PathView {
+    pub labels: Vec<String>,
+    pub adjacency: Vec<Vec<(usize, f64)>>
+}
+
+impl PathView {
+    pub fn new(graph: &_Graph, interpretation: &WeightInterpretation, weight_fn: Option<WeightFn>) -> Result<Self, InvalidLengthError> {
+        let labels = node_labels(graph);
+        let mut adjacency: Vec<Vec<(usize, f64)>> = Vec::new();
+
+        for (i, adj) in weighted_adjacency(graph).into_iter().enumerate() {
+            let mut merged: Vec<(usize, f64)> = Vec::new();
+
+            for (j, weight) in adj {
+                let length = edge_length(weight, interpretation, weight_fn);
+                check_length(length, &labels[i], &labels[j])?;
+                if !length.is_finite() {
+                    continue;
+                }
+
+                match merged.iter_mut().find(|(k, _)| *k == j) {
+                    Some(entry) => entry.1 = entry.1.min(length),
+                    None => merged.push((j, length)),
+                }
+            }
+
+            merged.sort_by(|a, b| a.0.cmp(&b.0));
+            adjacency.push(merged);
+        }
+
+        return Ok(PathView { labels: labels, adjacency: adjacency });
+    }
+
+    pub fn size(&self) -> usize {
+        return self.adjacency.len();
+    }
+
+    fn path_labels(&self, path: &Vec<usize>) -> Vec<String> {
+        return path.iter().map(|v| self.labels[*v].clone()).collect();
+    }
+
+    fn path_cost(&self, path: &Vec<usize>) -> f64 {
+        return path
+            .windows(2)
+            .map(|pair| self.adjacency[pair[0]].iter().find(|(j, _)| *j == pair[1]).unwrap().1)
+            .sum();
+    }
+}
+
+#[derive(PartialEq)]
+pub struct HeapEntry {
+    pub cost: f64,
+    pub node: usize
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal).then(other.node.cmp(&self.node));
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/*
+ * Heap based Dijkstra from 'source', skipping the nodes and arcs that are
+ * blocked. Returns the distance to every node (infinity when unreachable)
+ * and the predecessor of every reached node.
+ */
+pub fn dijkstra_tree(
+    view: &PathView,
+    source: usize,
+    blocked_nodes: &Vec<bool>,
+    blocked_arcs: &HashSet<(usize, usize)>
+) -> (Vec<f64>, Vec<Option<usize>>) {
+    let size = view.size();
+    let mut distances: Vec<f64> = vec![f64::INFINITY; size];
+    let mut previous: Vec<Option<usize>> = vec![None; size];
+    let mut heap: BinaryHeap<HeapEntry> = BinaryHeap::new();
+
+    distances[source] = 0.;
+    heap.push(HeapEntry { cost: 0., node: source });
+
+    while let Some(HeapEntry { cost, node }) = heap.pop() {
+        if cost > distances[node] {
+            continue;
+        }
+
+        for (next, weight) in &view.adjacency[node] {
+            if blocked_nodes[*next] || blocked_arcs.contains(&(node, *next)) {
+                continue;
+            }
+
+            let alt = cost + weight;
+            if alt < distances[*next] {
+                distances[*next] = alt;
+                previous[*next] = Some(node);
+                heap.push(HeapEntry { cost: alt, node: *next });
+            }
+        }
+    }
+
+    return (distances, previous);
+}
+
+/*
+ * Length of the shortest path from 'source_label' to every node, infinity
+ * for the nodes that cannot be reached.
+ */
+pub fn dijkstra(
+    graph: &_Graph,
+    source_label: &str,
+    interpretation: &WeightInterpretation,
+    weight_fn: Option<WeightFn>
+) -> Result<HashMap<String, f64>, InvalidLengthError> {
+    let view = PathView::new(graph, interpretation, weight_fn)?;
+    let source = position_of(graph, source_label);
+    let (distances, _) = dijkstra_tree(&view, source, &vec![false; view.size()], &HashSet::new());
+
+    return Ok(view.labels.into_iter().zip(distances).collect());
+}
+
+fn shortest_path(
+    view: &PathView,
+    source: usize,
+    target: usize,
+    blocked_nodes: &Vec<bool>,
+    blocked_arcs: &HashSet<(usize, usize)>
+) -> Option<Vec<usize>> {
+    let (distances, previous) = dijkstra_tree(view, source, blocked_nodes, blocked_arcs);
+
+    if distances[target].is_infinite() {
+        return None;
+    }
+
+    let mut path: Vec<usize> = vec![target];
+    let mut current = target;
+    while let Some(p) = previous[current] {
+        path.push(p);
+        current = p;
+    }
+    path.reverse();
+
+    return Some(path);
+}
+
+/*
+ * Yen's algorithm: the i-th shortest path is the cheapest of the candidates
+ * obtained by deviating from the (i-1)-th path at each of its nodes, after
+ * blocking the arcs used by the already found paths with the same root and
+ * the nodes of the root itself (so paths stay loopless).
+ */
+pub fn k_shortest_paths(
+    graph: &_Graph,
+    source_label: &str,
+    target_label: &str,
+    k: usize,
+    interpretation: &WeightInterpretation,
+    weight_fn: Option<WeightFn>
+) -> Result<Vec<(Vec<String>, f64)>, InvalidLengthError> {
+    let view = PathView::new(graph, interpretation, weight_fn)?;
+    let source = position_of(graph, source_label);
+    let target = position_of(graph, target_label);
+
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let no_nodes: Vec<bool> = vec![false; view.size()];
+
+    let mut found: Vec<Vec<usize>> = Vec::new();
+    let mut candidates: Vec<(f64, Vec<usize>)> = Vec::new();
+
+    match shortest_path(&view, source, target, &no_nodes, &HashSet::new()) {
+        Some(path) => found.push(path),
+        None => return Ok(Vec::new()),
+    }
+
+    while found.len() < k {
+        let last = found.last().unwrap().clone();
+
+        for i in 0..(last.len() - 1) {
+            let spur = last[i];
+            let root: Vec<usize> = last[..=i].to_vec();
+
+            let mut blocked_arcs: HashSet<(usize, usize)> = HashSet::new();
+            for path in &found {
+                if path.len() > i && path[..=i] == root[..] {
+                    blocked_arcs.insert((path[i], path[i + 1]));
+                }
+            }
+
+            let mut blocked_nodes: Vec<bool> = vec![false; view.size()];
+            for v in &root[..i] {
+                blocked_nodes[*v] = true;
+            }
+
+            if let Some(spur_path) = shortest_path(&view, spur, target, &blocked_nodes, &blocked_arcs) {
+                let mut total: Vec<usize> = root[..i].to_vec();
+                total.extend(spur_path);
+
+                if !candidates.iter().any(|(_, p)| *p == total) && !found.contains(&total) {
+                    candidates.push((view.path_cost(&total), total));
+                }
+            }
+        }
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap().then(a.1.len().cmp(&b.1.len())).then(a.1.cmp(&b.1)));
+        let (_, best) = candidates.remove(0);
+        found.push(best);
+    }
+
+    let paths: Vec<(Vec<String>, f64)> = found
+        .iter()
+        .map(|path| (view.path_labels(path), view.path_cost(path)))
+        .collect();
+
+    return Ok(paths);
+}
+
+/*
+ * Lazy depth-first enumeration of the simple paths from 'source' to
+ * 'target' with at most 'cutoff' connections. The iterator owns its copy of
+ * the adjacency, so it stays valid if the graph changes afterwards.
+ */
+pub struct SimplePaths {
+    labels: Vec<String>,
+    adjacency: Vec<Vec<usize>>,
+    target: usize,
+    cutoff: usize,
+    path: Vec<usize>,
+    on_path: Vec<bool>,
+    // Index of the next neighbour to try for each node of 'path'
+    next: Vec<usize>
+}
+
+impl SimplePaths {
+    pub fn new(graph: &_Graph, source_label: &str, target_label: &str, cutoff: Option<usize>) -> Self {
+        // Every length is 1, so the view cannot fail
+        let view = PathView::new(graph, &WeightInterpretation::Unweighted, None).unwrap();
+        let source = position_of(graph, source_label);
+        let target = position_of(graph, target_label);
+        let size = view.size();
+
+        let mut on_path: Vec<bool> = vec![false; size];
+        on_path[source] = true;
+
+        let path = if source == target { Vec::new() } else { vec![source] };
+
+        return SimplePaths {
+            labels: view.labels,
+            adjacency: view.adjacency.into_iter().map(|adj| adj.into_iter().map(|(j, _)| j).collect()).collect(),
+            target: target,
+            cutoff: cutoff.unwrap_or(size.saturating_sub(1)),
+            next: vec![0; path.len()],
+            path: path,
+            on_path: on_path
+        };
+    }
+}
+
+impl Iterator for SimplePaths {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Vec<String>> {
+        while let Some(v) = self.path.last().copied() {
+            let depth = self.path.len() - 1;
+            let k = self.next[depth];
+
+            if k >= self.adjacency[v].len() || depth >= self.cutoff {
+                self.on_path[v] = false;
+                self.path.pop();
+                self.next.pop();
+                continue;
+            }
+
+            self.next[depth] += 1;
+            let w = self.adjacency[v][k];
+
+            if self.on_path[w] {
+                continue;
+            }
+
+            if w == self.target {
+                let mut found: Vec<String> = self.path.iter().map(|u| self.labels[*u].clone()).collect();
+                found.push(self.labels[w].clone());
+                return Some(found);
+            }
+
+            self.path.push(w);
+            self.next.push(0);
+            self.on_path[w] = true;
+        }
+
+        return None;
+    }
+}
+
+pub fn all_simple_paths(graph: &_Graph, source_label: &str, target_label: &str, cutoff: Option<usize>) -> SimplePaths {
+    return SimplePaths::new(graph, source_label, target_label, cutoff);
+}
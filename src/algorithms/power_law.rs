@@ -0,0 +1,532 @@
+use std::collections::HashMap;
+use std::fmt;
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
+use crate::_Graph;
+use crate::algorithms::null_models::seeded_rng;
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum DegreeType {
+    All,
+    In,
+    Out,
+    Undirected
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotEnoughDataError;
+
+impl fmt::Display for NotEnoughDataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "At least two distinct positive values above xmin are needed to fit a power law")
+    }
+}
+
+impl std::error::Error for NotEnoughDataError {}
+
+/*
+ * Log-likelihood ratio between the power law and an alternative fitted on
+ * the same tail. Positive ratios favour the power law; the sign is only
+ * meaningful when 'p_value' is small. 'parameters' holds the maximum
+ * likelihood parameters of the alternative.
+ */
+#[pyclass(get_all)]
+#[derive(Clone, Debug)]
+pub struct LikelihoodRatio {
+    pub ratio: f64,
+    pub normalized_ratio: f64,
+    pub p_value: f64,
+    pub parameters: HashMap<String, f64>
+}
+
+/*
+ * Discrete power law p(x) = x^-alpha / zeta(alpha, xmin) for x >= xmin.
+ * 'p_value' is the share of bootstrap samples whose KS distance is at least
+ * the observed one (NaN without bootstrap): the power law is ruled out when
+ * it is small (Clauset et al. use 0.1).
+ */
+#[pyclass(get_all)]
+#[derive(Clone, Debug)]
+pub struct PowerLawFit {
+    pub xmin: usize,
+    pub alpha: f64,
+    pub ks_statistic: f64,
+    pub p_value: f64,
+    pub n: usize,
+    pub n_tail: usize,
+    pub lognormal: LikelihoodRatio,
+    pub exponential: LikelihoodRatio,
+    pub truncated_power_law: LikelihoodRatio
+}
+
+const ALPHA_RANGE: (f64, f64) = (1.0001, 20.);
+
+/*
+ * Hurwitz zeta function sum_{k >= 0} (k + q)^-s for s > 1: the first terms
+ * are added directly and the rest with the Euler–Maclaurin formula.
+ */
+fn hurwitz_zeta(s: f64, q: f64) -> f64 {
+    const BERNOULLI: [f64; 4] = [1. / 12., -1. / 720., 1. / 30240., -1. / 1209600.];
+
+    let mut sum = 0.;
+    let mut a = q;
+    for _ in 0..10 {
+        sum += a.powf(-s);
+        a += 1.;
+    }
+
+    sum += a.powf(1. - s) / (s - 1.) + 0.5 * a.powf(-s);
+
+    let mut rising = s;
+    let mut power = a.powf(-s - 1.);
+    for (j, b) in BERNOULLI.iter().enumerate() {
+        sum += b * rising * power;
+        let k = 2. * j as f64;
+        rising *= (s + k + 1.) * (s + k + 2.);
+        power /= a * a;
+    }
+
+    return sum;
+}
+
+/*
+ * Logarithm of the complementary error function, from Numerical Recipes'
+ * erfcc (relative error below 1.2e-7). Working with the logarithm avoids
+ * underflow far in the tail.
+ */
+fn ln_erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let ln_r = t.ln() + (-z * z - 1.26551223 + t * (1.00002368 + t * (0.37409196 + t * (0.09678418 + t * (-0.18628806
+        + t * (0.27886807 + t * (-1.13520398 + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))));
+
+    if x >= 0. {
+        return ln_r;
+    }
+
+    return (2. - ln_r.exp()).ln();
+}
+
+fn erfc(x: f64) -> f64 {
+    return ln_erfc(x).exp();
+}
+
+fn golden_section(f: impl Fn(f64) -> f64, mut low: f64, mut high: f64) -> f64 {
+    let ratio = (5f64.sqrt() - 1.) / 2.;
+    let mut a = high - ratio * (high - low);
+    let mut b = low + ratio * (high - low);
+    let (mut fa, mut fb) = (f(a), f(b));
+
+    while high - low > 1e-8 {
+        if fa < fb {
+            high = b;
+            b = a;
+            fb = fa;
+            a = high - ratio * (high - low);
+            fa = f(a);
+        } else {
+            low = a;
+            a = b;
+            fa = fb;
+            b = low + ratio * (high - low);
+            fb = f(b);
+        }
+    }
+
+    return (low + high) / 2.;
+}
+
+/*
+ * Nelder–Mead simplex minimisation in two dimensions. NaN values are
+ * treated as infinitely bad.
+ */
+fn nelder_mead(f: impl Fn([f64; 2]) -> f64, start: [f64; 2], step: [f64; 2]) -> [f64; 2] {
+    let value = |p: [f64; 2]| -> f64 {
+        let v = f(p);
+        return if v.is_nan() { f64::INFINITY } else { v };
+    };
+
+    let mut simplex: Vec<([f64; 2], f64)> = vec![
+        start,
+        [start[0] + step[0], start[1]],
+        [start[0], start[1] + step[1]],
+    ]
+    .into_iter()
+    .map(|p| (p, value(p)))
+    .collect();
+
+    for _ in 0..1000 {
+        simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        if (simplex[2].1 - simplex[0].1).abs() < 1e-10 {
+            break;
+        }
+
+        let centroid = [(simplex[0].0[0] + simplex[1].0[0]) / 2., (simplex[0].0[1] + simplex[1].0[1]) / 2.];
+        let towards = |t: f64| -> [f64; 2] {
+            return [centroid[0] + t * (simplex[2].0[0] - centroid[0]), centroid[1] + t * (simplex[2].0[1] - centroid[1])];
+        };
+
+        let reflected = towards(-1.);
+        let fr = value(reflected);
+
+        if fr < simplex[0].1 {
+            let expanded = towards(-2.);
+            let fe = value(expanded);
+            simplex[2] = if fe < fr { (expanded, fe) } else { (reflected, fr) };
+        } else if fr < simplex[1].1 {
+            simplex[2] = (reflected, fr);
+        } else {
+            let contracted = if fr < simplex[2].1 { towards(-0.5) } else { towards(0.5) };
+            let fc = value(contracted);
+
+            if fc < fr.min(simplex[2].1) {
+                simplex[2] = (contracted, fc);
+            } else {
+                let best = simplex[0].0;
+                for vertex in simplex.iter_mut().skip(1) {
+                    let p = [(vertex.0[0] + best[0]) / 2., (vertex.0[1] + best[1]) / 2.];
+                    *vertex = (p, value(p));
+                }
+            }
+        }
+    }
+
+    simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    return simplex[0].0;
+}
+
+fn power_law_alpha(tail: &[usize], xmin: usize) -> f64 {
+    let n = tail.len() as f64;
+    let sum_log: f64 = tail.iter().map(|x| (*x as f64).ln()).sum();
+    let negative_log_likelihood = |alpha: f64| n * hurwitz_zeta(alpha, xmin as f64).ln() + alpha * sum_log;
+
+    return golden_section(negative_log_likelihood, ALPHA_RANGE.0, ALPHA_RANGE.1);
+}
+
+/*
+ * Largest distance between the empirical and the fitted CDF, checked at
+ * every observed value. 'tail' is sorted.
+ */
+fn ks_distance(tail: &[usize], xmin: usize, alpha: f64) -> f64 {
+    let n = tail.len() as f64;
+    let normalization = hurwitz_zeta(alpha, xmin as f64);
+    let mut distance: f64 = 0.;
+    let mut i = 0;
+    // zeta(alpha, x + 1), kept up to date while x increases
+    let mut remaining = normalization;
+    let mut x = xmin - 1;
+
+    while i < tail.len() {
+        let value = tail[i];
+        while i < tail.len() && tail[i] == value {
+            i += 1;
+        }
+
+        if value - x <= 50 {
+            for k in (x + 1)..=value {
+                remaining -= (k as f64).powf(-alpha);
+            }
+        } else {
+            remaining = hurwitz_zeta(alpha, (value + 1) as f64);
+        }
+        x = value;
+
+        let empirical = i as f64 / n;
+        let fitted = 1. - remaining / normalization;
+        distance = distance.max((empirical - fitted).abs());
+    }
+
+    return distance;
+}
+
+/*
+ * Clauset–Shalizi–Newman estimate on sorted positive data: for every
+ * candidate xmin (or only the given one) alpha is the maximum likelihood
+ * estimate, and the xmin with the smallest KS distance is kept. Returns
+ * (xmin, alpha, ks distance, index where the tail starts).
+ */
+fn fit_sorted(data: &[usize], xmin: Option<usize>) -> Option<(usize, f64, f64, usize)> {
+    let mut candidates: Vec<usize> = match xmin {
+        Some(x) => vec![x.max(1)],
+        None => data.to_vec(),
+    };
+    candidates.dedup();
+
+    let mut best: Option<(usize, f64, f64, usize)> = None;
+
+    for candidate in candidates {
+        let start = data.partition_point(|x| *x < candidate);
+        let tail = &data[start..];
+        if tail.is_empty() || tail[0] == tail[tail.len() - 1] {
+            continue;
+        }
+
+        let alpha = power_law_alpha(tail, candidate);
+        let distance = ks_distance(tail, candidate, alpha);
+
+        if best.map_or(true, |(_, _, d, _)| distance < d) {
+            best = Some((candidate, alpha, distance, start));
+        }
+    }
+
+    return best;
+}
+
+/*
+ * Approximate discrete power-law sample (Clauset et al., appendix D):
+ * rounded continuous power law starting at xmin - 1/2.
+ */
+fn sample_power_law(xmin: usize, alpha: f64, rng: &mut StdRng) -> usize {
+    let r: f64 = rng.gen_range(0.0..1.0);
+    let x = ((xmin as f64 - 0.5) * (1. - r).powf(-1. / (alpha - 1.)) + 0.5).floor();
+
+    return x.min(1e15) as usize;
+}
+
+/*
+ * Semi-parametric bootstrap: synthetic data sets draw from the fitted power
+ * law with the probability of the tail, and from the observed values below
+ * xmin otherwise. Each one is fitted the same way as the data.
+ */
+fn bootstrap_p_value(
+    data: &[usize],
+    fixed_xmin: Option<usize>,
+    fit: (usize, f64, f64, usize),
+    n_bootstrap: usize,
+    seed: Option<u64>
+) -> f64 {
+    if n_bootstrap == 0 {
+        return f64::NAN;
+    }
+
+    let mut rng = seeded_rng(seed);
+    let (xmin, alpha, distance, start) = fit;
+    let body = &data[..start];
+    let tail_probability = (data.len() - start) as f64 / data.len() as f64;
+    let mut at_least = 0;
+
+    for _ in 0..n_bootstrap {
+        let mut synthetic: Vec<usize> = (0..data.len())
+            .map(|_| {
+                if body.is_empty() || rng.gen_bool(tail_probability) {
+                    return sample_power_law(xmin, alpha, &mut rng);
+                }
+                return body[rng.gen_range(0..body.len())];
+            })
+            .collect();
+        synthetic.sort();
+
+        if let Some((_, _, synthetic_distance, _)) = fit_sorted(&synthetic, fixed_xmin) {
+            if synthetic_distance >= distance {
+                at_least += 1;
+            }
+        }
+    }
+
+    return at_least as f64 / n_bootstrap as f64;
+}
+
+/*
+ * Vuong's test on the pointwise log-likelihood differences. Nested models
+ * (the truncated power law contains the power law) use the chi-squared test
+ * on the ratio instead.
+ */
+fn likelihood_ratio(power_law: &Vec<f64>, alternative: &Vec<f64>, nested: bool, parameters: HashMap<String, f64>) -> LikelihoodRatio {
+    let n = power_law.len() as f64;
+    let differences: Vec<f64> = power_law.iter().zip(alternative).map(|(a, b)| a - b).collect();
+    let ratio: f64 = differences.iter().sum();
+    let mean = ratio / n;
+    let std = (differences.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / n).sqrt();
+
+    let normalized_ratio = if std > 0. { ratio / (std * n.sqrt()) } else { 0. };
+    let p_value = if nested {
+        erfc(ratio.abs().sqrt())
+    } else if std > 0. {
+        erfc(normalized_ratio.abs() / 2f64.sqrt())
+    } else {
+        1.
+    };
+
+    return LikelihoodRatio { ratio: ratio, normalized_ratio: normalized_ratio, p_value: p_value, parameters: parameters };
+}
+
+fn exponential_log_likelihoods(tail: &[usize], xmin: usize) -> (Vec<f64>, HashMap<String, f64>) {
+    let mean_excess = tail.iter().map(|x| (x - xmin) as f64).sum::<f64>() / tail.len() as f64;
+    let lambda = if mean_excess > 0. { (1. + 1. / mean_excess).ln() } else { 700. };
+    let log_normalization = (-(-lambda).exp()).ln_1p();
+
+    let log_likelihoods = tail.iter().map(|x| log_normalization - lambda * (x - xmin) as f64).collect();
+
+    return (log_likelihoods, HashMap::from([("lambda".to_string(), lambda)]));
+}
+
+/*
+ * Log-normal discretised as P(x) = (S(x) - S(x + 1)) / S(xmin), S being the
+ * survival function of the continuous distribution.
+ */
+fn lognormal_log_likelihoods(tail: &[usize], xmin: usize) -> (Vec<f64>, HashMap<String, f64>) {
+    let ln_survival = |x: f64, mu: f64, sigma: f64| 0.5f64.ln() + ln_erfc((x.ln() - mu) / (sigma * 2f64.sqrt()));
+    let point = |x: usize, mu: f64, sigma: f64| -> f64 {
+        let ln_upper = ln_survival(x as f64, mu, sigma);
+        let ln_mass = ln_upper + (-(ln_survival((x + 1) as f64, mu, sigma) - ln_upper).exp()).ln_1p();
+        return ln_mass - ln_survival(xmin as f64, mu, sigma);
+    };
+
+    let logs: Vec<f64> = tail.iter().map(|x| (*x as f64).ln()).collect();
+    let n = logs.len() as f64;
+    let mean = logs.iter().sum::<f64>() / n;
+    let std = (logs.iter().map(|l| (l - mean).powi(2)).sum::<f64>() / n).sqrt().max(0.1);
+
+    let [mu, log_sigma] = nelder_mead(
+        |[mu, log_sigma]| -tail.iter().map(|x| point(*x, mu, log_sigma.exp())).sum::<f64>(),
+        [mean, std.ln()],
+        [0.5, 0.5]
+    );
+    let sigma = log_sigma.exp();
+
+    let log_likelihoods = tail.iter().map(|x| point(*x, mu, sigma)).collect();
+
+    return (log_likelihoods, HashMap::from([("mu".to_string(), mu), ("sigma".to_string(), sigma)]));
+}
+
+/*
+ * ln of sum_{x >= xmin} x^-alpha e^(-lambda x). Terms are added directly
+ * until they become negligible; the rest, if any, is the integral of the
+ * summand (computed in log space) plus the Euler–Maclaurin boundary term.
+ */
+fn truncated_log_normalization(alpha: f64, lambda: f64, xmin: usize) -> f64 {
+    let x0 = xmin as f64;
+    let relative = |x: f64| (-alpha * (x / x0).ln() - lambda * (x - x0)).exp();
+
+    let mut sum = 0.;
+    let mut x = x0;
+    for _ in 0..1000 {
+        let term = relative(x);
+        sum += term;
+        x += 1.;
+        if term < 1e-15 * sum {
+            return sum.ln() - alpha * x0.ln() - lambda * x0;
+        }
+    }
+
+    let steps = 4000;
+    let width = 40. / steps as f64;
+    let integrand = |u: f64| relative(u.exp()) * u.exp();
+    let mut integral = integrand(x.ln()) + integrand(x.ln() + 40.);
+    for i in 1..steps {
+        let weight = if i % 2 == 1 { 4. } else { 2. };
+        integral += weight * integrand(x.ln() + i as f64 * width);
+    }
+    sum += integral * width / 3. + relative(x) / 2.;
+
+    return sum.ln() - alpha * x0.ln() - lambda * x0;
+}
+
+fn truncated_power_law_log_likelihoods(tail: &[usize], xmin: usize, alpha: f64) -> (Vec<f64>, HashMap<String, f64>) {
+    let point = |x: usize, alpha: f64, lambda: f64, log_normalization: f64| {
+        -alpha * (x as f64).ln() - lambda * x as f64 - log_normalization
+    };
+
+    let mean = tail.iter().map(|x| *x as f64).sum::<f64>() / tail.len() as f64;
+
+    let [alpha, log_lambda] = nelder_mead(
+        |[alpha, log_lambda]| {
+            let lambda = log_lambda.exp();
+            let log_normalization = truncated_log_normalization(alpha, lambda, xmin);
+            return -tail.iter().map(|x| point(*x, alpha, lambda, log_normalization)).sum::<f64>();
+        },
+        [alpha, (0.1 / mean).ln()],
+        [0.2, 1.]
+    );
+    let lambda = log_lambda.exp();
+    let log_normalization = truncated_log_normalization(alpha, lambda, xmin);
+
+    let log_likelihoods = tail.iter().map(|x| point(*x, alpha, lambda, log_normalization)).collect();
+
+    return (log_likelihoods, HashMap::from([("alpha".to_string(), alpha), ("lambda".to_string(), lambda)]));
+}
+
+/*
+ * Fits a discrete power law to the positive values of 'data' following
+ * Clauset, Shalizi & Newman (2009): xmin minimises the KS distance unless it
+ * is given, alpha is the maximum likelihood estimate and the goodness of
+ * fit comes from 'n_bootstrap' synthetic data sets. The tail is also
+ * compared with log-normal, exponential and truncated power law fits.
+ */
+pub fn fit_power_law(
+    data: &Vec<usize>,
+    xmin: Option<usize>,
+    n_bootstrap: usize,
+    seed: Option<u64>
+) -> Result<PowerLawFit, NotEnoughDataError> {
+    let mut sorted: Vec<usize> = data.iter().copied().filter(|x| *x > 0).collect();
+    sorted.sort();
+
+    let fixed_xmin = xmin;
+    let fit = fit_sorted(&sorted, fixed_xmin).ok_or(NotEnoughDataError)?;
+    let (xmin, alpha, distance, start) = fit;
+    let tail = &sorted[start..];
+
+    let log_normalization = hurwitz_zeta(alpha, xmin as f64).ln();
+    let power_law: Vec<f64> = tail.iter().map(|x| -alpha * (*x as f64).ln() - log_normalization).collect();
+
+    let (lognormal, lognormal_parameters) = lognormal_log_likelihoods(tail, xmin);
+    let (exponential, exponential_parameters) = exponential_log_likelihoods(tail, xmin);
+    let (truncated, truncated_parameters) = truncated_power_law_log_likelihoods(tail, xmin, alpha);
+
+    return Ok(PowerLawFit {
+        xmin: xmin,
+        alpha: alpha,
+        ks_statistic: distance,
+        p_value: bootstrap_p_value(&sorted, fixed_xmin, fit, n_bootstrap, seed),
+        n: sorted.len(),
+        n_tail: tail.len(),
+        lognormal: likelihood_ratio(&power_law, &lognormal, false, lognormal_parameters),
+        exponential: likelihood_ratio(&power_law, &exponential, false, exponential_parameters),
+        truncated_power_law: likelihood_ratio(&power_law, &truncated, true, truncated_parameters)
+    });
+}
+
+/*
+ * Degree of every node: every connection touching it (All, self-loops
+ * counted twice), directed connections arriving at it (In) or leaving it
+ * (Out), or undirected connections (Undirected, as in compute_degrees).
+ */
+pub fn degree_sequence(graph: &_Graph, degree: &DegreeType) -> Vec<usize> {
+    let mut degrees: Vec<usize> = vec![0; graph.nodes.len()];
+
+    for edge in graph.edges() {
+        let (from, to, directed) = (edge.from, edge.to, edge.directed);
+
+        match degree {
+            DegreeType::All => {
+                degrees[from] += 1;
+                degrees[to] += 1;
+            },
+            DegreeType::In if directed => degrees[to] += 1,
+            DegreeType::Out if directed => degrees[from] += 1,
+            DegreeType::Undirected if !directed => {
+                degrees[from] += 1;
+                if to != from {
+                    degrees[to] += 1;
+                }
+            },
+            _ => {}
+        }
+    }
+
+    return degrees;
+}
+
+pub fn degree_power_law(
+    graph: &_Graph,
+    degree: &DegreeType,
+    xmin: Option<usize>,
+    n_bootstrap: usize,
+    seed: Option<u64>
+) -> Result<PowerLawFit, NotEnoughDataError> {
+    return fit_power_law(&degree_sequence(graph, degree), xmin, n_bootstrap, seed);
+}
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use pyo3::prelude::*;
+use crate::_Graph;
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum EdgeAggregation {
+    Sum,
+    Count,
+    Mean
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupNameError {
+    pub label: String
+}
+
+impl fmt::Display for GroupNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Node '{}' is not in the groups but its label is already a group name", self.label)
+    }
+}
+
+impl std::error::Error for GroupNameError {}
+
+/*
+ * Collapses every group of 'groups' (node label -> group name) into a single
+ * node labelled with the group name. Nodes missing from the mapping form a
+ * group of their own, named after their label, which must not be the name
+ * of a group of the mapping (GroupNameError). Groups come in the order of
+ * their first node, placed at the centroid of their members when all of
+ * them have coordinates.
+ *
+ * All the connections between two groups become one connection, directed
+ * ones kept apart from undirected ones, whose weight is the sum, number or
+ * mean of their weights. Connections inside a group become a self-loop when
+ * 'self_loops' is set and are dropped otherwise.
+ */
+pub fn quotient_graph(
+    graph: &_Graph,
+    groups: &HashMap<String, String>,
+    aggregation: &EdgeAggregation,
+    self_loops: bool
+) -> Result<_Graph, GroupNameError> {
+    let mapped_names: HashSet<&String> = graph.nodes
+        .iter()
+        .filter_map(|n| groups.get(&n.borrow().label))
+        .collect();
+
+    let mut group_names: Vec<String> = Vec::new();
+    let mut group_index: HashMap<String, usize> = HashMap::new();
+    let mut members: Vec<Vec<usize>> = Vec::new();
+    let mut node_group: Vec<usize> = Vec::new();
+
+    for (i, n) in graph.nodes.iter().enumerate() {
+        let label = &n.borrow().label;
+        let name = match groups.get(label) {
+            Some(name) => name,
+            None => {
+                if mapped_names.contains(label) {
+                    return Err(GroupNameError { label: label.clone() });
+                }
+                label
+            }
+        };
+
+        let g = *group_index.entry(name.clone()).or_insert_with(|| {
+            group_names.push(name.clone());
+            members.push(Vec::new());
+            group_names.len() - 1
+        });
+
+        members[g].push(i);
+        node_group.push(g);
+    }
+
+    let mut quotient = _Graph::default();
+
+    for (g, name) in group_names.iter().enumerate() {
+        let coordinates: Vec<(f64, f64)> = members[g]
+            .iter()
+            .filter_map(|v| {
+                let node = graph.nodes[*v].borrow();
+                return node.x.zip(node.y);
+            })
+            .collect();
+
+        if coordinates.len() == members[g].len() {
+            let count = coordinates.len() as f64;
+            let x = coordinates.iter().map(|c| c.0).sum::<f64>() / count;
+            let y = coordinates.iter().map(|c| c.1).sum::<f64>() / count;
+            quotient.add_node_with_pos(name.clone(), x, y);
+        }
+        else {
+            quotient.add_node(name.clone());
+        }
+    }
+
+    // (from group, to group, directed) -> (total weight, number of connections)
+    let mut aggregated: HashMap<(usize, usize, bool), (f64, usize)> = HashMap::new();
+    let mut order: Vec<(usize, usize, bool)> = Vec::new();
+
+    for edge in graph.edges() {
+        let directed = edge.directed;
+        let (mut a, mut b) = (node_group[edge.from], node_group[edge.to]);
+        if a == b && !self_loops {
+            continue;
+        }
+        if !directed && a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        let entry = aggregated.entry((a, b, directed)).or_insert_with(|| {
+            order.push((a, b, directed));
+            (0., 0)
+        });
+        entry.0 += edge.weight as f64;
+        entry.1 += 1;
+    }
+
+    for key in order {
+        let (total, count) = aggregated[&key];
+        let weight = match aggregation {
+            EdgeAggregation::Sum => total,
+            EdgeAggregation::Count => count as f64,
+            EdgeAggregation::Mean => total / count as f64,
+        };
+
+        quotient.create_connection(group_names[key.0].clone(), group_names[key.1].clone(), weight as f32, Some(key.2));
+    }
+
+    return Ok(quotient);
+}
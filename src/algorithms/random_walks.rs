@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use rand::Rng;
+use rand::rngs::StdRng;
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, weighted_adjacency};
+use crate::algorithms::null_models::seeded_rng;
+
+/*
+ * Picks one of the candidates with probability proportional to its weight.
+ * Returns None when no candidate has a positive weight.
+ */
+fn pick_weighted(candidates: &Vec<(usize, f64)>, rng: &mut StdRng) -> Option<usize> {
+    let total: f64 = candidates.iter().map(|(_, w)| w.max(0.)).sum();
+
+    if total <= 0. {
+        return None;
+    }
+
+    let mut target = rng.gen_range(0.0..total);
+    for (node, weight) in candidates {
+        let weight = weight.max(0.);
+        if target < weight {
+            return Some(*node);
+        }
+        target -= weight;
+    }
+
+    return candidates.iter().rev().find(|(_, w)| *w > 0.).map(|(n, _)| *n);
+}
+
+/*
+ * node2vec walks (Grover & Leskovec). After moving t -> v, the next node x
+ * is chosen with weight w(v, x) multiplied by 1/p if x is t, 1 if x is a
+ * neighbour of t and 1/q otherwise. With p = q = 1 this is a plain
+ * (first-order) random walk. Walks stop early at nodes without exits.
+ */
+pub fn node2vec_walks(
+    graph: &_Graph,
+    walk_length: usize,
+    walks_per_node: usize,
+    p: f64,
+    q: f64,
+    weighted: bool,
+    seed: Option<u64>
+) -> Vec<Vec<String>> {
+    let labels = node_labels(graph);
+    let adjacency = weighted_adjacency(graph);
+    let neighbour_sets: Vec<HashSet<usize>> = adjacency
+        .iter()
+        .map(|adj| adj.iter().map(|(j, _)| *j).collect())
+        .collect();
+    let mut rng = seeded_rng(seed);
+    let mut walks: Vec<Vec<String>> = Vec::new();
+
+    for _ in 0..walks_per_node {
+        for start in 0..adjacency.len() {
+            let mut walk: Vec<usize> = vec![start];
+
+            while walk.len() < walk_length {
+                let current = walk[walk.len() - 1];
+                let previous = if walk.len() > 1 { Some(walk[walk.len() - 2]) } else { None };
+
+                let candidates: Vec<(usize, f64)> = adjacency[current]
+                    .iter()
+                    .map(|(x, w)| {
+                        let mut weight = if weighted { *w as f64 } else { 1. };
+
+                        if let Some(t) = previous {
+                            if *x == t {
+                                weight /= p;
+                            } else if !neighbour_sets[t].contains(x) {
+                                weight /= q;
+                            }
+                        }
+
+                        (*x, weight)
+                    })
+                    .collect();
+
+                match pick_weighted(&candidates, &mut rng) {
+                    Some(next) => walk.push(next),
+                    None => break,
+                }
+            }
+
+            walks.push(walk.into_iter().map(|i| labels[i].clone()).collect());
+        }
+    }
+
+    return walks;
+}
+
+pub fn random_walks(graph: &_Graph, walk_length: usize, walks_per_node: usize, weighted: bool, seed: Option<u64>) -> Vec<Vec<String>> {
+    return node2vec_walks(graph, walk_length, walks_per_node, 1., 1., weighted, seed);
+}
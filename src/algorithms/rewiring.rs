@@ -0,0 +1,97 @@
+use rand::rngs::StdRng;
+use crate::_Graph;
+use crate::algorithms::null_models::{directed_edge_swap, double_edge_swap, seeded_rng, SWAPS_PER_EDGE};
+use crate::algorithms::subgraph::{build_subgraph, copy_graph, graph_edges};
+
+/*
+ * Observed metric value against the values measured on 'n_samples'
+ * degree preserving rewirings of the graph. The p-value is two-sided:
+ * the share of samples at least as far from the mean as the observed value,
+ * with the usual +1 correction.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Significance {
+    pub observed: f64,
+    pub mean: f64,
+    pub std: f64,
+    pub z_score: f64,
+    pub p_value: f64
+}
+
+/*
+ * Rewires the connections with the given 'directed' flag among themselves,
+ * leaving the other ones and the self-loops untouched. Each connection
+ * keeps its weight and its position in the list of connections.
+ */
+fn swap_connections(graph: &_Graph, directed: bool, n_swaps: Option<usize>, rng: &mut StdRng) -> _Graph {
+    let mut edges = graph_edges(graph);
+    let swappable: Vec<usize> = (0..edges.len())
+        .filter(|i| edges[*i].directed == directed && edges[*i].from != edges[*i].to)
+        .collect();
+
+    let mut pairs: Vec<(usize, usize)> = swappable.iter().map(|i| (edges[*i].from, edges[*i].to)).collect();
+    let n_swaps = n_swaps.unwrap_or(pairs.len() * SWAPS_PER_EDGE);
+
+    if directed {
+        directed_edge_swap(&mut pairs, n_swaps, rng);
+    } else {
+        double_edge_swap(&mut pairs, n_swaps, rng);
+    }
+
+    for (i, (from, to)) in swappable.into_iter().zip(pairs) {
+        edges[i].from = from;
+        edges[i].to = to;
+    }
+
+    let nodes: Vec<usize> = (0..graph.nodes.len()).collect();
+
+    return build_subgraph(graph, &nodes, &edges);
+}
+
+/*
+ * New graph where the undirected connections were rewired with double edge
+ * swaps, so every node keeps its degree. Defaults to ten swaps per connection.
+ */
+pub fn double_edge_swap_graph(graph: &_Graph, n_swaps: Option<usize>, seed: Option<u64>) -> _Graph {
+    return swap_connections(graph, false, n_swaps, &mut seeded_rng(seed));
+}
+
+/*
+ * Same as double_edge_swap_graph for the directed connections, keeping
+ * every in-degree and out-degree.
+ */
+pub fn directed_edge_swap_graph(graph: &_Graph, n_swaps: Option<usize>, seed: Option<u64>) -> _Graph {
+    return swap_connections(graph, true, n_swaps, &mut seeded_rng(seed));
+}
+
+fn rewire(graph: &_Graph, rng: &mut StdRng) -> _Graph {
+    let undirected_swapped = swap_connections(graph, false, None, rng);
+
+    return swap_connections(&undirected_swapped, true, None, rng);
+}
+
+pub fn significance(
+    graph: &_Graph,
+    metric_fn: &mut dyn FnMut(_Graph) -> f64,
+    n_samples: usize,
+    seed: Option<u64>
+) -> Significance {
+    let mut rng = seeded_rng(seed);
+    let observed = metric_fn(copy_graph(graph));
+
+    let samples: Vec<f64> = (0..n_samples).map(|_| metric_fn(rewire(graph, &mut rng))).collect();
+
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let std = (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+    let z_score = if std > 0. { (observed - mean) / std } else { f64::NAN };
+    let extreme = samples.iter().filter(|x| (*x - mean).abs() >= (observed - mean).abs()).count() as f64;
+
+    return Significance {
+        observed: observed,
+        mean: mean,
+        std: std,
+        z_score: z_score,
+        p_value: (extreme + 1.) / (n + 1.)
+    };
+}
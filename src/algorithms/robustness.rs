@@ -0,0 +1,329 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rand::seq::SliceRandom;
+use crate::_Graph;
+use crate::algorithms::adjacency::{neighbours, node_labels};
+use crate::algorithms::centrality::betweenness;
+use crate::algorithms::null_models::seeded_rng;
+use crate::algorithms::paths::{check_length, edge_length, HeapEntry, InvalidLengthError, WeightFn, WeightInterpretation};
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum RemovalTarget {
+    Nodes,
+    Edges
+}
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum RemovalStrategy {
+    Random,
+    Degree,
+    Betweenness
+}
+
+/*
+ * One row per step, step 0 being the intact graph. 'removed' holds the
+ * label of the removed node, or the two labels of the removed connection.
+ * 'r_index' is the mean giant component fraction over the removal steps
+ * (Schneider et al.), so lower values mean a more fragile graph.
+ */
+#[pyclass(get_all)]
+#[derive(Clone, Debug)]
+pub struct RobustnessResult {
+    pub step: Vec<usize>,
+    pub removed: Vec<Vec<String>>,
+    pub fraction_removed: Vec<f64>,
+    pub giant_component_size: Vec<usize>,
+    pub giant_component_fraction: Vec<f64>,
+    pub average_path_length: Vec<f64>,
+    pub r_index: f64
+}
+
+#[pymethods]
+impl RobustnessResult {
+    /*
+     * Columns as a dict of lists, ready for pandas.DataFrame or plotting.
+     */
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let table = PyDict::new(py);
+        table.set_item("step", self.step.clone())?;
+        table.set_item("removed", self.removed.clone())?;
+        table.set_item("fraction_removed", self.fraction_removed.clone())?;
+        table.set_item("giant_component_size", self.giant_component_size.clone())?;
+        table.set_item("giant_component_fraction", self.giant_component_fraction.clone())?;
+        table.set_item("average_path_length", self.average_path_length.clone())?;
+
+        return Ok(table);
+    }
+}
+
+fn giant_component_size(adjacency: &Vec<Vec<usize>>, alive: &Vec<bool>) -> usize {
+    let mut visited: Vec<bool> = vec![false; adjacency.len()];
+    let mut largest = 0;
+
+    for root in 0..adjacency.len() {
+        if visited[root] || !alive[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        let mut size = 0;
+        let mut q: VecDeque<usize> = VecDeque::from([root]);
+
+        while let Some(v) = q.pop_front() {
+            size += 1;
+            for w in &adjacency[v] {
+                if !visited[*w] {
+                    visited[*w] = true;
+                    q.push_back(*w);
+                }
+            }
+        }
+
+        largest = largest.max(size);
+    }
+
+    return largest;
+}
+
+/*
+ * Length of every undirected connection, keyed by (smaller, larger) node
+ * position. Parallel connections keep the shortest length, and connections
+ * with an infinite length are left out.
+ */
+fn connection_lengths(
+    graph: &_Graph,
+    labels: &Vec<String>,
+    interpretation: &WeightInterpretation,
+    weight_fn: Option<WeightFn>
+) -> Result<HashMap<(usize, usize), f64>, InvalidLengthError> {
+    let mut lengths: HashMap<(usize, usize), f64> = HashMap::new();
+
+    for edge in graph.edges() {
+        let length = edge_length(edge.weight, interpretation, weight_fn);
+        check_length(length, &labels[edge.from], &labels[edge.to])?;
+        if !length.is_finite() {
+            continue;
+        }
+
+        let key = (edge.from.min(edge.to), edge.from.max(edge.to));
+        lengths.entry(key)
+            .and_modify(|l| *l = l.min(length))
+            .or_insert(length);
+    }
+
+    return Ok(lengths);
+}
+
+/*
+ * Mean shortest path length between all pairs of nodes that can reach each
+ * other, NaN when there are no such pairs.
+ */
+fn average_path_length(adjacency: &Vec<Vec<usize>>, alive: &Vec<bool>, lengths: &HashMap<(usize, usize), f64>) -> f64 {
+    let size = adjacency.len();
+    let mut total = 0.;
+    let mut pairs = 0.;
+
+    for source in 0..size {
+        if !alive[source] {
+            continue;
+        }
+
+        let mut distance: Vec<f64> = vec![f64::INFINITY; size];
+        let mut heap: BinaryHeap<HeapEntry> = BinaryHeap::new();
+        distance[source] = 0.;
+        heap.push(HeapEntry { cost: 0., node: source });
+
+        while let Some(HeapEntry { cost, node }) = heap.pop() {
+            if cost > distance[node] {
+                continue;
+            }
+
+            for w in &adjacency[node] {
+                let Some(length) = lengths.get(&(node.min(*w), node.max(*w))) else {
+                    continue;
+                };
+
+                if cost + length < distance[*w] {
+                    distance[*w] = cost + length;
+                    heap.push(HeapEntry { cost: distance[*w], node: *w });
+                }
+            }
+        }
+
+        for v in 0..size {
+            if v != source && distance[v].is_finite() {
+                total += distance[v];
+                pairs += 1.;
+            }
+        }
+    }
+
+    if pairs == 0. {
+        return f64::NAN;
+    }
+
+    return total / pairs;
+}
+
+fn node_scores(adjacency: &Vec<Vec<usize>>, strategy: &RemovalStrategy) -> Vec<f64> {
+    match strategy {
+        RemovalStrategy::Betweenness => return betweenness(adjacency).0,
+        _ => return adjacency.iter().map(|adj| adj.len() as f64).collect(),
+    }
+}
+
+/*
+ * Edges are ranked by edge betweenness, or by the product of the degrees
+ * of their ends for the degree strategy.
+ */
+fn edge_scores(adjacency: &Vec<Vec<usize>>, strategy: &RemovalStrategy) -> HashMap<(usize, usize), f64> {
+    match strategy {
+        RemovalStrategy::Betweenness => return betweenness(adjacency).1,
+        _ => {
+            let mut scores: HashMap<(usize, usize), f64> = HashMap::new();
+            for (v, adj) in adjacency.iter().enumerate() {
+                for w in adj {
+                    if v < *w {
+                        scores.insert((v, *w), (adjacency[v].len() * adjacency[*w].len()) as f64);
+                    }
+                }
+            }
+            return scores;
+        }
+    }
+}
+
+fn highest<T: Copy + Ord>(scores: impl Iterator<Item = (T, f64)>) -> Option<T> {
+    return scores
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(b.0.cmp(&a.0)))
+        .map(|(item, _)| item);
+}
+
+/*
+ * Removes nodes or connections one at a time, in the order given by the
+ * strategy, and measures the undirected view of what is left after each
+ * removal. Rankings are computed once on the intact graph, or after every
+ * removal when 'adaptive' is set. Stops after 'max_removals' steps or when
+ * nothing is left to remove. Path lengths use the connection weights as in
+ * the shortest path algorithms; Unweighted counts hops. Negative or NaN
+ * lengths are an error.
+ */
+pub fn robustness(
+    graph: &_Graph,
+    target: &RemovalTarget,
+    strategy: &RemovalStrategy,
+    adaptive: bool,
+    max_removals: Option<usize>,
+    seed: Option<u64>,
+    interpretation: &WeightInterpretation,
+    weight_fn: Option<WeightFn>
+) -> Result<RobustnessResult, InvalidLengthError> {
+    let labels = node_labels(graph);
+    let mut adjacency = neighbours(graph);
+    let lengths = connection_lengths(graph, &labels, interpretation, weight_fn)?;
+    let size = adjacency.len();
+    let mut alive: Vec<bool> = vec![true; size];
+    let mut rng = seeded_rng(seed);
+
+    let total = match target {
+        RemovalTarget::Nodes => size,
+        RemovalTarget::Edges => adjacency.iter().map(|adj| adj.len()).sum::<usize>() / 2,
+    };
+    let steps = max_removals.unwrap_or(total).min(total);
+
+    // Fixed removal order for the random and non adaptive strategies
+    let mut order: Vec<(usize, usize)> = match target {
+        RemovalTarget::Nodes => {
+            let mut nodes: Vec<usize> = (0..size).collect();
+            if *strategy == RemovalStrategy::Random {
+                nodes.shuffle(&mut rng);
+            } else {
+                let scores = node_scores(&adjacency, strategy);
+                nodes.sort_by(|a, b| scores[*b].partial_cmp(&scores[*a]).unwrap().then(a.cmp(b)));
+            }
+            nodes.into_iter().map(|v| (v, v)).collect()
+        },
+        RemovalTarget::Edges => {
+            let scores = edge_scores(&adjacency, strategy);
+            let mut edges: Vec<(usize, usize)> = scores.keys().copied().collect();
+            edges.sort();
+            if *strategy == RemovalStrategy::Random {
+                edges.shuffle(&mut rng);
+            } else {
+                edges.sort_by(|a, b| scores[b].partial_cmp(&scores[a]).unwrap().then(a.cmp(b)));
+            }
+            edges
+        }
+    };
+    order.reverse();
+
+    let mut result = RobustnessResult {
+        step: Vec::new(),
+        removed: Vec::new(),
+        fraction_removed: Vec::new(),
+        giant_component_size: Vec::new(),
+        giant_component_fraction: Vec::new(),
+        average_path_length: Vec::new(),
+        r_index: 0.
+    };
+
+    let mut record = |step: usize, removed: Vec<String>, adjacency: &Vec<Vec<usize>>, alive: &Vec<bool>| {
+        let giant = giant_component_size(adjacency, alive);
+        result.step.push(step);
+        result.removed.push(removed);
+        result.fraction_removed.push(if total == 0 { 0. } else { step as f64 / total as f64 });
+        result.giant_component_size.push(giant);
+        result.giant_component_fraction.push(if size == 0 { 0. } else { giant as f64 / size as f64 });
+        result.average_path_length.push(average_path_length(adjacency, alive, &lengths));
+    };
+
+    record(0, Vec::new(), &adjacency, &alive);
+
+    let dynamic = adaptive && *strategy != RemovalStrategy::Random;
+
+    for step in 1..=steps {
+        let next = if dynamic {
+            match target {
+                RemovalTarget::Nodes => {
+                    let scores = node_scores(&adjacency, strategy);
+                    highest((0..size).filter(|v| alive[*v]).map(|v| (v, scores[v]))).map(|v| (v, v))
+                },
+                RemovalTarget::Edges => highest(edge_scores(&adjacency, strategy).into_iter()),
+            }
+        } else {
+            order.pop()
+        };
+
+        let (u, v) = match next {
+            Some(item) => item,
+            None => break,
+        };
+
+        let removed = match target {
+            RemovalTarget::Nodes => {
+                for w in std::mem::take(&mut adjacency[u]) {
+                    adjacency[w].retain(|x| *x != u);
+                }
+                alive[u] = false;
+                vec![labels[u].clone()]
+            },
+            RemovalTarget::Edges => {
+                adjacency[u].retain(|x| *x != v);
+                adjacency[v].retain(|x| *x != u);
+                vec![labels[u].clone(), labels[v].clone()]
+            }
+        };
+
+        record(step, removed, &adjacency, &alive);
+    }
+
+    let removal_steps = result.step.len() - 1;
+    if removal_steps > 0 {
+        result.r_index = result.giant_component_fraction[1..].iter().sum::<f64>() / removal_steps as f64;
+    }
+
+    return Ok(result);
+}
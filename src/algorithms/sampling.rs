@@ -0,0 +1,215 @@
+use std::collections::VecDeque;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use crate::_Graph;
+use crate::algorithms::adjacency::{neighbours, position_of};
+use crate::algorithms::null_models::seeded_rng;
+use crate::algorithms::subgraph::{build_subgraph, graph_edges, induced_subgraph};
+use crate::graph_core::edges::EdgeRef;
+
+/*
+ * All the samplers below return a new graph with at most 'target_size'
+ * nodes, copied with their labels and coordinates. Except for the random
+ * edge sampler, every connection between two sampled nodes is kept.
+ * Traversals use the undirected view of the graph and jump to a random
+ * unvisited node when they cannot reach new nodes anymore.
+ */
+
+fn random_unvisited(visited: &Vec<bool>, rng: &mut StdRng) -> Option<usize> {
+    let unvisited: Vec<usize> = (0..visited.len()).filter(|v| !visited[*v]).collect();
+
+    return unvisited.choose(rng).copied();
+}
+
+pub fn random_node_sample(graph: &_Graph, target_size: usize, seed: Option<u64>) -> _Graph {
+    let mut rng = seeded_rng(seed);
+    let mut nodes: Vec<usize> = (0..graph.nodes.len()).collect();
+    nodes.shuffle(&mut rng);
+    nodes.truncate(target_size);
+
+    return induced_subgraph(graph, &nodes);
+}
+
+/*
+ * Picks connections uniformly at random, keeping only the picked ones and
+ * their ends, until 'target_size' nodes are covered. A connection that
+ * would exceed the target size is skipped.
+ */
+pub fn random_edge_sample(graph: &_Graph, target_size: usize, seed: Option<u64>) -> _Graph {
+    let mut rng = seeded_rng(seed);
+    let mut edges = graph_edges(graph);
+    edges.shuffle(&mut rng);
+
+    let mut selected: Vec<bool> = vec![false; graph.nodes.len()];
+    let mut count = 0;
+    let mut kept: Vec<EdgeRef> = Vec::new();
+
+    for edge in edges {
+        let mut new_nodes = if selected[edge.from] { 0 } else { 1 };
+        if edge.to != edge.from && !selected[edge.to] {
+            new_nodes += 1;
+        }
+
+        if count + new_nodes > target_size {
+            continue;
+        }
+
+        selected[edge.from] = true;
+        selected[edge.to] = true;
+        count += new_nodes;
+        kept.push(edge);
+
+        if count == target_size {
+            break;
+        }
+    }
+
+    let nodes: Vec<usize> = (0..selected.len()).filter(|v| selected[*v]).collect();
+
+    return build_subgraph(graph, &nodes, &kept);
+}
+
+/*
+ * Breadth-first snowball from 'start_node' (random when not given), going
+ * at most 'depth' hops away from each starting node.
+ */
+pub fn snowball_sample(
+    graph: &_Graph,
+    target_size: usize,
+    depth: Option<usize>,
+    start_node: Option<&str>,
+    seed: Option<u64>
+) -> _Graph {
+    let mut rng = seeded_rng(seed);
+    let adjacency = neighbours(graph);
+    let mut visited: Vec<bool> = vec![false; adjacency.len()];
+    let mut sampled: Vec<usize> = Vec::new();
+    let mut start = match start_node {
+        Some(label) => Some(position_of(graph, label)),
+        None => random_unvisited(&visited, &mut rng),
+    };
+
+    while let Some(root) = start {
+        if sampled.len() >= target_size {
+            break;
+        }
+
+        visited[root] = true;
+        sampled.push(root);
+        let mut q: VecDeque<(usize, usize)> = VecDeque::from([(root, 0)]);
+
+        while let Some((v, hops)) = q.pop_front() {
+            if depth.map_or(false, |d| hops >= d) {
+                continue;
+            }
+
+            for w in &adjacency[v] {
+                if sampled.len() >= target_size {
+                    break;
+                }
+
+                if !visited[*w] {
+                    visited[*w] = true;
+                    sampled.push(*w);
+                    q.push_back((*w, hops + 1));
+                }
+            }
+        }
+
+        start = random_unvisited(&visited, &mut rng);
+    }
+
+    return induced_subgraph(graph, &sampled);
+}
+
+/*
+ * Random walk that goes back to its starting node with probability
+ * 'restart_probability' at every step. When the walk stops finding new
+ * nodes (after 100 steps per node of the graph) it starts over from a
+ * random unvisited node.
+ */
+pub fn random_walk_sample(graph: &_Graph, target_size: usize, restart_probability: f64, seed: Option<u64>) -> _Graph {
+    let mut rng = seeded_rng(seed);
+    let adjacency = neighbours(graph);
+    let size = adjacency.len();
+    let mut visited: Vec<bool> = vec![false; size];
+    let mut sampled: Vec<usize> = Vec::new();
+    let max_idle_steps = 100 * size;
+
+    while sampled.len() < target_size.min(size) {
+        let start = random_unvisited(&visited, &mut rng).unwrap();
+        visited[start] = true;
+        sampled.push(start);
+
+        let mut current = start;
+        let mut idle_steps = 0;
+
+        while sampled.len() < target_size && idle_steps < max_idle_steps {
+            if adjacency[current].is_empty() || rng.gen_bool(restart_probability.clamp(0., 1.)) {
+                current = start;
+                idle_steps += 1;
+                if adjacency[current].is_empty() {
+                    break;
+                }
+                continue;
+            }
+
+            current = *adjacency[current].choose(&mut rng).unwrap();
+            if visited[current] {
+                idle_steps += 1;
+            } else {
+                visited[current] = true;
+                sampled.push(current);
+                idle_steps = 0;
+            }
+        }
+    }
+
+    return induced_subgraph(graph, &sampled);
+}
+
+/*
+ * Forest fire (Leskovec & Faloutsos): every burning node sets fire to a
+ * geometrically distributed number (mean p / (1 - p)) of its unburned
+ * neighbours. When the fire dies out a new one starts at a random node.
+ * 'burn_probability' is clamped to [0, 0.99], so at most 99 neighbours are
+ * burned on average and every fire ends.
+ */
+pub fn forest_fire_sample(graph: &_Graph, target_size: usize, burn_probability: f64, seed: Option<u64>) -> _Graph {
+    let mut rng = seeded_rng(seed);
+    let adjacency = neighbours(graph);
+    let size = adjacency.len();
+    let p = burn_probability.clamp(0., 0.99);
+    let mut burned: Vec<bool> = vec![false; size];
+    let mut sampled: Vec<usize> = Vec::new();
+
+    while sampled.len() < target_size.min(size) {
+        let start = random_unvisited(&burned, &mut rng).unwrap();
+        burned[start] = true;
+        sampled.push(start);
+        let mut q: VecDeque<usize> = VecDeque::from([start]);
+
+        while let Some(v) = q.pop_front() {
+            let mut spread = 0;
+            while rng.gen_bool(p) {
+                spread += 1;
+            }
+
+            let mut candidates: Vec<usize> = adjacency[v].iter().copied().filter(|w| !burned[*w]).collect();
+            candidates.shuffle(&mut rng);
+
+            for w in candidates.into_iter().take(spread) {
+                if sampled.len() >= target_size {
+                    break;
+                }
+
+                burned[w] = true;
+                sampled.push(w);
+                q.push_back(w);
+            }
+        }
+    }
+
+    return induced_subgraph(graph, &sampled);
+}
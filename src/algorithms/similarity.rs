@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, weighted_adjacency};
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum SimilarityMethod {
+    SimRank,
+    Cosine,
+    Pearson
+}
+
+/*
+ * Rows of the adjacency matrix built like generate_adjacency_matrix:
+ * directed connections go one way, undirected connections both ways.
+ */
+fn adjacency_rows(adjacency: &Vec<Vec<(usize, f32)>>) -> Vec<Vec<f64>> {
+    let size = adjacency.len();
+    let mut rows: Vec<Vec<f64>> = vec![vec![0.; size]; size];
+
+    for (i, adj) in adjacency.iter().enumerate() {
+        for (j, weight) in adj {
+            rows[i][*j] = *weight as f64;
+        }
+    }
+
+    return rows;
+}
+
+/*
+ * S(a, b) = decay / (|I(a)| |I(b)|) * sum of S(i, j) over the in-neighbours
+ * i of a and j of b, with S(a, a) = 1. Each iteration goes through the
+ * partial sums P(a, j) = sum of S(i, j) over i in I(a), so it costs
+ * O(n^2 * d) instead of O(n^2 * d^2).
+ */
+fn simrank(adjacency: &Vec<Vec<(usize, f32)>>, iterations: usize, decay: f64) -> Vec<Vec<f64>> {
+    let size = adjacency.len();
+    let mut in_neighbours: Vec<Vec<usize>> = vec![Vec::new(); size];
+
+    for (i, adj) in adjacency.iter().enumerate() {
+        for (j, _) in adj {
+            if !in_neighbours[*j].contains(&i) {
+                in_neighbours[*j].push(i);
+            }
+        }
+    }
+
+    let mut scores: Vec<Vec<f64>> = vec![vec![0.; size]; size];
+    for v in 0..size {
+        scores[v][v] = 1.;
+    }
+
+    for _ in 0..iterations {
+        let mut partial: Vec<Vec<f64>> = vec![vec![0.; size]; size];
+        for a in 0..size {
+            for i in &in_neighbours[a] {
+                for j in 0..size {
+                    partial[a][j] += scores[*i][j];
+                }
+            }
+        }
+
+        let mut next: Vec<Vec<f64>> = vec![vec![0.; size]; size];
+        for a in 0..size {
+            next[a][a] = 1.;
+
+            if in_neighbours[a].is_empty() {
+                continue;
+            }
+
+            for b in (a + 1)..size {
+                if in_neighbours[b].is_empty() {
+                    continue;
+                }
+
+                let total: f64 = in_neighbours[b].iter().map(|j| partial[a][*j]).sum();
+                let norm = (in_neighbours[a].len() * in_neighbours[b].len()) as f64;
+                next[a][b] = decay * total / norm;
+                next[b][a] = next[a][b];
+            }
+        }
+
+        scores = next;
+    }
+
+    return scores;
+}
+
+fn cosine(rows: &Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let size = rows.len();
+    let norms: Vec<f64> = rows.iter().map(|r| r.iter().map(|x| x * x).sum::<f64>().sqrt()).collect();
+    let mut scores: Vec<Vec<f64>> = vec![vec![0.; size]; size];
+
+    for a in 0..size {
+        for b in a..size {
+            if norms[a] == 0. || norms[b] == 0. {
+                continue;
+            }
+
+            let dot: f64 = rows[a].iter().zip(rows[b].iter()).map(|(x, y)| x * y).sum();
+            scores[a][b] = dot / (norms[a] * norms[b]);
+            scores[b][a] = scores[a][b];
+        }
+    }
+
+    return scores;
+}
+
+/*
+ * Pearson correlation between adjacency rows. Rows without variation
+ * (isolated nodes, or nodes linked to everyone) get 0.
+ */
+fn pearson(rows: &Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let size = rows.len();
+    let centred: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|r| {
+            let mean = r.iter().sum::<f64>() / size as f64;
+            r.iter().map(|x| x - mean).collect()
+        })
+        .collect();
+
+    return cosine(&centred);
+}
+
+/*
+ * Dense node-by-node similarity matrix, in the same node order as
+ * generate_adjacency_matrix. 'iterations' and 'decay' are only used by SimRank.
+ */
+pub fn similarity_matrix(graph: &_Graph, method: &SimilarityMethod, iterations: usize, decay: f64) -> Vec<Vec<f64>> {
+    let adjacency = weighted_adjacency(graph);
+
+    match method {
+        SimilarityMethod::SimRank => return simrank(&adjacency, iterations, decay),
+        SimilarityMethod::Cosine => return cosine(&adjacency_rows(&adjacency)),
+        SimilarityMethod::Pearson => return pearson(&adjacency_rows(&adjacency)),
+    }
+}
+
+/*
+ * Sparse version of similarity_matrix: for every node, the 'k' most similar
+ * other nodes with a non-zero score, from the most to the least similar.
+ */
+pub fn most_similar(
+    graph: &_Graph,
+    method: &SimilarityMethod,
+    k: usize,
+    iterations: usize,
+    decay: f64
+) -> HashMap<String, Vec<(String, f64)>> {
+    let labels = node_labels(graph);
+    let scores = similarity_matrix(graph, method, iterations, decay);
+    let mut similar: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+
+    for (a, row) in scores.iter().enumerate() {
+        let mut ranked: Vec<(usize, f64)> = row
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(b, score)| *b != a && *score != 0.)
+            .collect();
+        ranked.sort_by(|x, y| y.1.partial_cmp(&x.1).unwrap().then(x.0.cmp(&y.0)));
+        ranked.truncate(k);
+
+        let neighbours: Vec<(String, f64)> = ranked.into_iter().map(|(b, score)| (labels[b].clone(), score)).collect();
+        similar.insert(labels[a].clone(), neighbours);
+    }
+
+    return similar;
+}
@@ -0,0 +1,81 @@
+use crate::_Graph;
+use crate::graph_core::edges::EdgeRef;
+
+/*
+ * Builds a new graph from node positions (indexes in graph.nodes) and
+ * edges, keeping labels, coordinates and the times of temporal connections.
+ */
+pub fn build_subgraph(graph: &_Graph, nodes: &Vec<usize>, edges: &Vec<EdgeRef>) -> _Graph {
+    let mut subgraph = _Graph::default();
+
+    for i in nodes {
+        let node = graph.nodes[*i].borrow();
+
+        if node.x.is_some() && node.y.is_some() {
+            subgraph.add_node_with_pos(node.label.clone(), node.x.unwrap(), node.y.unwrap());
+        }
+        else {
+            subgraph.add_node(node.label.clone());
+        }
+    }
+
+    for edge in edges {
+        let from_label = graph.nodes[edge.from].borrow().label.clone();
+        let to_label = graph.nodes[edge.to].borrow().label.clone();
+
+        match edge.time {
+            Some((start, end)) => subgraph.create_temporal_connection(from_label, to_label, edge.weight, Some(edge.directed), start, Some(end)),
+            None => subgraph.create_connection(from_label, to_label, edge.weight, Some(edge.directed)),
+        }
+    }
+
+    return subgraph;
+}
+
+pub fn edge_subgraph(graph: &_Graph, edges: &Vec<EdgeRef>) -> _Graph {
+    let mut used: Vec<bool> = vec![false; graph.nodes.len()];
+
+    for edge in edges {
+        used[edge.from] = true;
+        used[edge.to] = true;
+    }
+
+    let nodes: Vec<usize> = (0..used.len()).filter(|i| used[*i]).collect();
+
+    return build_subgraph(graph, &nodes, edges);
+}
+
+/*
+ * Every connection of the graph, in the order they are stored.
+ */
+pub fn graph_edges(graph: &_Graph) -> Vec<EdgeRef> {
+    return graph.edges().collect();
+}
+
+/*
+ * Independent copy of the graph: same nodes, coordinates and connections,
+ * but no node shared with the original.
+ */
+pub fn copy_graph(graph: &_Graph) -> _Graph {
+    let nodes: Vec<usize> = (0..graph.nodes.len()).collect();
+
+    return build_subgraph(graph, &nodes, &graph_edges(graph));
+}
+
+/*
+ * Subgraph with the given nodes and every connection between them. Nodes
+ * keep their order in the original graph.
+ */
+pub fn induced_subgraph(graph: &_Graph, nodes: &Vec<usize>) -> _Graph {
+    let mut selected: Vec<bool> = vec![false; graph.nodes.len()];
+    for v in nodes {
+        selected[*v] = true;
+    }
+
+    let ordered: Vec<usize> = (0..selected.len()).filter(|v| selected[*v]).collect();
+    let edges: Vec<EdgeRef> = graph.edges()
+        .filter(|edge| selected[edge.from] && selected[edge.to])
+        .collect();
+
+    return build_subgraph(graph, &ordered, &edges);
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use crate::_Graph;
+use crate::algorithms::adjacency::{neighbours, weighted_adjacency};
+use crate::algorithms::dag::strongly_connected_components;
+
+/*
+ * Graph-level overview returned by describe. Statistics over an empty set
+ * (weights of a graph without connections, degrees of an empty graph) are None.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSummary {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub directed_edge_count: usize,
+    pub undirected_edge_count: usize,
+    pub density: f64,
+    pub weight_min: Option<f64>,
+    pub weight_max: Option<f64>,
+    pub weight_mean: Option<f64>,
+    pub weight_median: Option<f64>,
+    pub degree_min: Option<usize>,
+    pub degree_max: Option<usize>,
+    pub degree_mean: Option<f64>,
+    pub degree_median: Option<f64>,
+    pub weakly_connected_components: usize,
+    pub strongly_connected_components: usize,
+    pub isolated_nodes: usize,
+    pub self_loop_count: usize,
+    pub multi_edge_count: usize,
+    pub build_time_ms: Option<f64>
+}
+
+fn median(sorted: &Vec<f64>) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        return Some((sorted[middle - 1] + sorted[middle]) / 2.);
+    }
+
+    return Some(sorted[middle]);
+}
+
+fn weakly_connected_components(adjacency: &Vec<Vec<usize>>) -> usize {
+    let mut visited: Vec<bool> = vec![false; adjacency.len()];
+    let mut components = 0;
+
+    for root in 0..adjacency.len() {
+        if visited[root] {
+            continue;
+        }
+
+        components += 1;
+        visited[root] = true;
+        let mut q: VecDeque<usize> = VecDeque::from([root]);
+
+        while let Some(v) = q.pop_front() {
+            for w in &adjacency[v] {
+                if !visited[*w] {
+                    visited[*w] = true;
+                    q.push_back(*w);
+                }
+            }
+        }
+    }
+
+    return components;
+}
+
+/*
+ * Density counts a directed connection as one of the n (n - 1) possible
+ * arcs and an undirected one as two, so it matches the usual definition on
+ * purely directed or purely undirected graphs. Degrees count every
+ * connection touching the node, self-loops twice. A multi-edge is any
+ * connection repeating an earlier one between the same nodes, in the same
+ * direction for directed connections.
+ */
+pub fn describe(graph: &_Graph) -> GraphSummary {
+    let size = graph.nodes.len();
+    let mut directed_edge_count = 0;
+    let mut undirected_edge_count = 0;
+    let mut weights: Vec<f64> = Vec::new();
+    let mut degrees: Vec<usize> = vec![0; size];
+    let mut seen: HashMap<(usize, usize, bool), usize> = HashMap::new();
+    let mut self_loop_count = 0;
+
+    for edge in graph.edges() {
+        let (from, to) = (edge.from, edge.to);
+
+        if edge.directed {
+            directed_edge_count += 1;
+        } else {
+            undirected_edge_count += 1;
+        }
+        weights.push(edge.weight as f64);
+
+        degrees[from] += 1;
+        degrees[to] += 1;
+
+        if from == to {
+            self_loop_count += 1;
+        }
+
+        let key = if edge.directed { (from, to, true) } else { (from.min(to), from.max(to), false) };
+        *seen.entry(key).or_insert(0) += 1;
+    }
+
+    let density = if size < 2 {
+        0.
+    } else {
+        (directed_edge_count + 2 * undirected_edge_count) as f64 / (size * (size - 1)) as f64
+    };
+    weights.sort_by(|a, b| a.total_cmp(b));
+
+    let multi_edge_count = seen.values().map(|count| count - 1).sum();
+    let isolated_nodes = degrees.iter().filter(|d| **d == 0).count();
+
+    let mut sorted_degrees: Vec<f64> = degrees.iter().map(|d| *d as f64).collect();
+    sorted_degrees.sort_by(|a, b| a.total_cmp(b));
+
+    let arcs: Vec<Vec<usize>> = weighted_adjacency(graph)
+        .into_iter()
+        .map(|adj| adj.into_iter().map(|(j, _)| j).collect())
+        .collect();
+
+    return GraphSummary {
+        node_count: size,
+        edge_count: directed_edge_count + undirected_edge_count,
+        directed_edge_count: directed_edge_count,
+        undirected_edge_count: undirected_edge_count,
+        density: density,
+        weight_min: weights.first().copied(),
+        weight_max: weights.last().copied(),
+        weight_mean: if weights.is_empty() { None } else { Some(weights.iter().sum::<f64>() / weights.len() as f64) },
+        weight_median: median(&weights),
+        degree_min: degrees.iter().min().copied(),
+        degree_max: degrees.iter().max().copied(),
+        degree_mean: if size == 0 { None } else { Some(degrees.iter().sum::<usize>() as f64 / size as f64) },
+        degree_median: median(&sorted_degrees),
+        weakly_connected_components: weakly_connected_components(&neighbours(graph)),
+        strongly_connected_components: strongly_connected_components(&arcs, &vec![true; size]).len(),
+        isolated_nodes: isolated_nodes,
+        self_loop_count: self_loop_count,
+        multi_edge_count: multi_edge_count,
+        build_time_ms: graph.build_time_ms
+    };
+}
+
+fn format_number(value: Option<f64>) -> String {
+    match value {
+        Some(v) if v.fract() == 0. => return format!("{}", v),
+        Some(v) => return format!("{:.4}", v).trim_end_matches('0').trim_end_matches('.').to_string(),
+        None => return "-".to_string(),
+    }
+}
+
+impl fmt::Display for GraphSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Graph summary")?;
+        writeln!(f, "  nodes         {} ({} isolated)", self.node_count, self.isolated_nodes)?;
+        writeln!(f, "  edges         {} ({} directed, {} undirected)", self.edge_count, self.directed_edge_count, self.undirected_edge_count)?;
+        writeln!(f, "  self-loops    {}", self.self_loop_count)?;
+        writeln!(f, "  multi-edges   {}", self.multi_edge_count)?;
+        writeln!(f, "  density       {:.4}", self.density)?;
+        writeln!(
+            f,
+            "  weight        min {}, max {}, mean {}, median {}",
+            format_number(self.weight_min),
+            format_number(self.weight_max),
+            format_number(self.weight_mean),
+            format_number(self.weight_median)
+        )?;
+        writeln!(
+            f,
+            "  degree        min {}, max {}, mean {}, median {}",
+            format_number(self.degree_min.map(|d| d as f64)),
+            format_number(self.degree_max.map(|d| d as f64)),
+            format_number(self.degree_mean),
+            format_number(self.degree_median)
+        )?;
+        writeln!(f, "  components    {} weak, {} strong", self.weakly_connected_components, self.strongly_connected_components)?;
+        match self.build_time_ms {
+            Some(ms) => write!(f, "  build time    {:.2} ms", ms),
+            None => write!(f, "  build time    -"),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{BinaryHeap, HashMap};
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, position_of};
+use crate::algorithms::paths::HeapEntry;
+use crate::algorithms::subgraph::build_subgraph;
+use crate::graph_core::edges::EdgeRef;
+
+/*
+ * Earliest start and latest end among the temporal connections, None when
+ * no connection has a time.
+ */
+pub fn time_span(graph: &_Graph) -> Option<(f64, f64)> {
+    let mut span: Option<(f64, f64)> = None;
+
+    for edge in graph.edges() {
+        if let Some((start, end)) = edge.time {
+            span = match span {
+                Some((s, e)) => Some((s.min(start), e.max(end))),
+                None => Some((start, end)),
+            };
+        }
+    }
+
+    return span;
+}
+
+/*
+ * Copy of the graph with every node (labels and coordinates) and only the
+ * connections accepted by 'keep', which keep their times.
+ */
+fn temporal_subgraph(graph: &_Graph, keep: impl Fn(&EdgeRef) -> bool) -> _Graph {
+    let nodes: Vec<usize> = (0..graph.nodes.len()).collect();
+    let edges: Vec<EdgeRef> = graph.edges().filter(|e| keep(e)).collect();
+
+    return build_subgraph(graph, &nodes, &edges);
+}
+
+/*
+ * Graph as it is at 'time': every node, and the connections whose interval
+ * contains it. Connections without a time are always present.
+ */
+pub fn snapshot(graph: &_Graph, time: f64) -> _Graph {
+    return temporal_subgraph(graph, |edge| match edge.time {
+        Some((start, end)) => start <= time && time <= end,
+        None => true,
+    });
+}
+
+/*
+ * Graph of the period [start, end): every node, and the connections that
+ * exist at some moment of it. Connections without a time are always present.
+ */
+pub fn window(graph: &_Graph, start: f64, end: f64) -> _Graph {
+    return temporal_subgraph(graph, |edge| match edge.time {
+        Some((s, e)) => s < end && e >= start,
+        None => true,
+    });
+}
+
+/*
+ * Start and end of consecutive windows of length 'width', 'step' apart,
+ * until a window starts after 'end'.
+ */
+pub struct WindowSchedule {
+    next: f64,
+    end: f64,
+    width: f64,
+    step: f64
+}
+
+impl Iterator for WindowSchedule {
+    type Item = (f64, f64);
+
+    fn next(&mut self) -> Option<(f64, f64)> {
+        if self.next > self.end {
+            return None;
+        }
+
+        let start = self.next;
+        self.next += self.step;
+
+        return Some((start, start + self.width));
+    }
+}
+
+/*
+ * Windows covering the graph from 'start' to 'end', by default the time span
+ * of its connections. 'step' defaults to 'width' (windows that do not
+ * overlap).
+ */
+pub fn window_schedule(graph: &_Graph, width: f64, step: Option<f64>, start: Option<f64>, end: Option<f64>) -> WindowSchedule {
+    let step = step.unwrap_or(width);
+    if !(width > 0.) || !(step > 0.) {
+        panic!("Window width and step must be positive");
+    }
+
+    let span = time_span(graph);
+    let start = start.or(span.map(|s| s.0));
+    let end = end.or(span.map(|s| s.1));
+
+    match (start, end) {
+        (Some(start), Some(end)) => return WindowSchedule { next: start, end: end, width: width, step: step },
+        _ => return WindowSchedule { next: f64::INFINITY, end: f64::NEG_INFINITY, width: width, step: step },
+    }
+}
+
+pub fn sliding_windows(
+    graph: &_Graph,
+    width: f64,
+    step: Option<f64>,
+    start: Option<f64>,
+    end: Option<f64>
+) -> impl Iterator<Item = (f64, f64, _Graph)> + '_ {
+    return window_schedule(graph, width, step, start, end).map(move |(t0, t1)| (t0, t1, window(graph, t0, t1)));
+}
+
+/*
+ * Earliest arrival search: a connection can be taken at any moment of its
+ * interval that is not before the arrival at its start node, and takes no
+ * time. Directed connections are followed in their direction, undirected
+ * ones both ways, connections without a time at any moment. Returns the
+ * earliest arrival at every node and the (node, time) each one was reached
+ * from.
+ */
+fn earliest_arrival(graph: &_Graph, source: usize, start_time: f64) -> (Vec<f64>, Vec<Option<(usize, f64)>>) {
+    let size = graph.nodes.len();
+    let mut adjacency: Vec<Vec<(usize, Option<(f64, f64)>)>> = vec![Vec::new(); size];
+
+    for edge in graph.edges() {
+        adjacency[edge.from].push((edge.to, edge.time));
+        if !edge.directed && edge.from != edge.to {
+            adjacency[edge.to].push((edge.from, edge.time));
+        }
+    }
+
+    let mut arrival: Vec<f64> = vec![f64::INFINITY; size];
+    let mut previous: Vec<Option<(usize, f64)>> = vec![None; size];
+    let mut heap: BinaryHeap<HeapEntry> = BinaryHeap::new();
+
+    arrival[source] = start_time;
+    heap.push(HeapEntry { cost: start_time, node: source });
+
+    while let Some(HeapEntry { cost, node }) = heap.pop() {
+        if cost > arrival[node] {
+            continue;
+        }
+
+        for (next, time) in &adjacency[node] {
+            let departure = match time {
+                Some((start, end)) => {
+                    if *end < cost {
+                        continue;
+                    }
+                    cost.max(*start)
+                },
+                None => cost,
+            };
+
+            if departure < arrival[*next] {
+                arrival[*next] = departure;
+                previous[*next] = Some((node, departure));
+                heap.push(HeapEntry { cost: departure, node: *next });
+            }
+        }
+    }
+
+    return (arrival, previous);
+}
+
+/*
+ * Searches start at 'start_time', by default the beginning of the time span
+ * (0 when no connection has a time).
+ */
+fn default_start(graph: &_Graph, start_time: Option<f64>) -> f64 {
+    return start_time.or(time_span(graph).map(|s| s.0)).unwrap_or(0.);
+}
+
+/*
+ * Nodes reachable from 'source_label' through time-respecting paths
+ * (connections taken in non-decreasing time order), with the earliest time
+ * each one can be reached. The source is reached at the start time.
+ */
+pub fn temporal_reachability(graph: &_Graph, source_label: &str, start_time: Option<f64>) -> HashMap<String, f64> {
+    let labels = node_labels(graph);
+    let source = position_of(graph, source_label);
+    let (arrival, _) = earliest_arrival(graph, source, default_start(graph, start_time));
+
+    return arrival
+        .into_iter()
+        .enumerate()
+        .filter(|(_, time)| time.is_finite())
+        .map(|(v, time)| (labels[v].clone(), time))
+        .collect();
+}
+
+/*
+ * Time-respecting path from the source to the target that arrives the
+ * earliest, as (from, to, time) hops, or None when the target cannot be
+ * reached.
+ */
+pub fn time_respecting_path(
+    graph: &_Graph,
+    source_label: &str,
+    target_label: &str,
+    start_time: Option<f64>
+) -> Option<Vec<(String, String, f64)>> {
+    let labels = node_labels(graph);
+    let source = position_of(graph, source_label);
+    let target = position_of(graph, target_label);
+    let (arrival, previous) = earliest_arrival(graph, source, default_start(graph, start_time));
+
+    if !arrival[target].is_finite() {
+        return None;
+    }
+
+    let mut hops: Vec<(String, String, f64)> = Vec::new();
+    let mut current = target;
+
+    while current != source {
+        let (from, time) = previous[current].unwrap();
+        hops.push((labels[from].clone(), labels[current].clone(), time));
+        current = from;
+    }
+    hops.reverse();
+
+    return Some(hops);
+}
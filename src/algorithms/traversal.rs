@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+use pyo3::prelude::*;
+use crate::_Graph;
+use crate::algorithms::adjacency::{node_labels, position_of, weighted_successors};
+
+/*
+ * Returned by the visitor callbacks. Prune skips what is behind the event:
+ * the connections of a discovered node, or the target of a tree edge, which
+ * stays undiscovered. Stop ends the traversal right away.
+ */
+#[pyclass(eq, eq_int)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum VisitAction {
+    #[default]
+    Continue,
+    Prune,
+    Stop
+}
+
+/*
+ * Events of dfs_visit and bfs_visit. Every callback does nothing by default,
+ * so a visitor only implements the events it needs. Depths count the hops
+ * from the start node.
+ */
+pub trait TraversalVisitor {
+    fn discover(&mut self, _node: &str, _depth: usize) -> VisitAction {
+        return VisitAction::Continue;
+    }
+
+    fn finish(&mut self, _node: &str, _depth: usize) -> VisitAction {
+        return VisitAction::Continue;
+    }
+
+    fn tree_edge(&mut self, _from: &str, _to: &str, _weight: f32) -> VisitAction {
+        return VisitAction::Continue;
+    }
+
+    fn back_edge(&mut self, _from: &str, _to: &str, _weight: f32) -> VisitAction {
+        return VisitAction::Continue;
+    }
+
+    fn cross_edge(&mut self, _from: &str, _to: &str, _weight: f32) -> VisitAction {
+        return VisitAction::Continue;
+    }
+}
+
+/*
+ * Visitor used by dfs and bfs: the labels in discovery order.
+ */
+#[derive(Default)]
+pub struct DiscoveryOrder {
+    pub order: Vec<String>
+}
+
+impl TraversalVisitor for DiscoveryOrder {
+    fn discover(&mut self, node: &str, _depth: usize) -> VisitAction {
+        self.order.push(node.to_string());
+        return VisitAction::Continue;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Colour {
+    White,
+    Gray,
+    Black
+}
+
+/*
+ * Depth-first search following every connection in the direction it was
+ * created, like _Graph::dfs. Connections to a node that is still open are
+ * back edges; connections to a finished node (forward or cross edges) are
+ * reported as cross edges. Nodes at 'max_depth' are discovered but their
+ * connections are not followed.
+ */
+pub fn dfs_visit(graph: &_Graph, start_node_label: &str, visitor: &mut dyn TraversalVisitor, max_depth: Option<usize>) {
+    Traversal::new(graph, start_node_label).dfs(visitor, max_depth);
+}
+
+/*
+ * Breadth-first search following every connection in the direction it was
+ * created, like _Graph::bfs. A node is finished once all its connections
+ * were looked at. Connections to an already discovered node are back edges
+ * when it is an ancestor in the search tree (or the node itself), cross
+ * edges otherwise.
+ */
+pub fn bfs_visit(graph: &_Graph, start_node_label: &str, visitor: &mut dyn TraversalVisitor, max_depth: Option<usize>) {
+    Traversal::new(graph, start_node_label).bfs(visitor, max_depth);
+}
+
+/*
+ * Labels and connections a traversal needs, copied from the graph so the
+ * search itself does not hold it. The visitors of the Python bindings may
+ * change the graph while it runs; the search goes on over the graph as it
+ * was when it started.
+ */
+pub struct Traversal {
+    labels: Vec<String>,
+    successors: Vec<Vec<(usize, f32)>>,
+    start: usize
+}
+
+impl Traversal {
+    pub fn new(graph: &_Graph, start_node_label: &str) -> Traversal {
+        return Traversal {
+            labels: node_labels(graph),
+            successors: weighted_successors(graph),
+            start: position_of(graph, start_node_label)
+        };
+    }
+
+    pub fn dfs(&self, visitor: &mut dyn TraversalVisitor, max_depth: Option<usize>) {
+        let labels = &self.labels;
+        let successors = &self.successors;
+        let size = successors.len();
+        let start = self.start;
+
+        let mut colour: Vec<Colour> = vec![Colour::White; size];
+        let mut depth: Vec<usize> = vec![0; size];
+        let mut expand: Vec<bool> = vec![true; size];
+        // (node, next connection to look at)
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+
+        colour[start] = Colour::Gray;
+        match visitor.discover(&labels[start], 0) {
+            VisitAction::Stop => return,
+            VisitAction::Prune => expand[start] = false,
+            VisitAction::Continue => {}
+        }
+        stack.push((start, 0));
+
+        while let Some((v, next)) = stack.last_mut() {
+            let v = *v;
+            let can_expand = expand[v] && max_depth.map_or(true, |m| depth[v] < m);
+
+            if !can_expand || *next >= successors[v].len() {
+                stack.pop();
+                colour[v] = Colour::Black;
+                if visitor.finish(&labels[v], depth[v]) == VisitAction::Stop {
+                    return;
+                }
+                continue;
+            }
+
+            let (w, weight) = successors[v][*next];
+            *next += 1;
+
+            let action = match colour[w] {
+                Colour::Gray => visitor.back_edge(&labels[v], &labels[w], weight),
+                Colour::Black => visitor.cross_edge(&labels[v], &labels[w], weight),
+                Colour::White => {
+                    match visitor.tree_edge(&labels[v], &labels[w], weight) {
+                        VisitAction::Continue => {
+                            colour[w] = Colour::Gray;
+                            depth[w] = depth[v] + 1;
+                            stack.push((w, 0));
+
+                            let discovered = visitor.discover(&labels[w], depth[w]);
+                            if discovered == VisitAction::Prune {
+                                expand[w] = false;
+                            }
+                            discovered
+                        },
+                        other => other,
+                    }
+                }
+            };
+
+            if action == VisitAction::Stop {
+                return;
+            }
+        }
+    }
+
+    pub fn bfs(&self, visitor: &mut dyn TraversalVisitor, max_depth: Option<usize>) {
+        let labels = &self.labels;
+        let successors = &self.successors;
+        let size = successors.len();
+        let start = self.start;
+
+        let mut discovered: Vec<bool> = vec![false; size];
+        let mut parent: Vec<Option<usize>> = vec![None; size];
+        let mut depth: Vec<usize> = vec![0; size];
+        let mut expand: Vec<bool> = vec![true; size];
+        let mut q: VecDeque<usize> = VecDeque::new();
+
+        let is_ancestor = |parent: &Vec<Option<usize>>, ancestor: usize, mut node: usize| -> bool {
+            loop {
+                if node == ancestor {
+                    return true;
+                }
+                match parent[node] {
+                    Some(p) => node = p,
+                    None => return false,
+                }
+            }
+        };
+
+        discovered[start] = true;
+        match visitor.discover(&labels[start], 0) {
+            VisitAction::Stop => return,
+            VisitAction::Prune => expand[start] = false,
+            VisitAction::Continue => {}
+        }
+        q.push_back(start);
+
+        while let Some(v) = q.pop_front() {
+            if expand[v] && max_depth.map_or(true, |m| depth[v] < m) {
+                for (w, weight) in &successors[v] {
+                    let w = *w;
+
+                    let action = if discovered[w] {
+                        if is_ancestor(&parent, w, v) {
+                            visitor.back_edge(&labels[v], &labels[w], *weight)
+                        } else {
+                            visitor.cross_edge(&labels[v], &labels[w], *weight)
+                        }
+                    } else {
+                        match visitor.tree_edge(&labels[v], &labels[w], *weight) {
+                            VisitAction::Continue => {
+                                discovered[w] = true;
+                                parent[w] = Some(v);
+                                depth[w] = depth[v] + 1;
+                                q.push_back(w);
+
+                                let action = visitor.discover(&labels[w], depth[w]);
+                                if action == VisitAction::Prune {
+                                    expand[w] = false;
+                                }
+                                action
+                            },
+                            other => other,
+                        }
+                    };
+
+                    if action == VisitAction::Stop {
+                        return;
+                    }
+                }
+            }
+
+            if visitor.finish(&labels[v], depth[v]) == VisitAction::Stop {
+                return;
+            }
+        }
+    }
+}
@@ -46,7 +46,8 @@ pub struct Work {
     pub display_name: Option<String>,
     pub authorships: Option<Vec<AuthorShip>>,
     pub keywords: Option<Vec<KeyWord>>,
-    pub referenced_works: Option<Vec<String>>
+    pub referenced_works: Option<Vec<String>>,
+    pub publication_year: Option<i32>
 }
 
 
@@ -57,4 +58,6 @@ pub enum OpenAlexGraphType {
     KeywordCooccurrence,
     WorkCocitation,
     AuthorCocitation,
+    AuthorWork,
+    KeywordWork,
 }
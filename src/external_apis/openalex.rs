@@ -120,6 +120,43 @@ fn openalex_make_batch_work_request(work_ids: HashSet<String>, api_key: &str) ->
     return Ok(all_works);
 }
 
+/*
+ * Widens the span of publication years of the works shared by a pair. Works
+ * without a year leave the span untouched.
+ */
+fn extend_years(edges_years: &mut HashMap<(String, String), Option<(i32, i32)>>, key: (String, String), year: Option<i32>) {
+    let span = edges_years.entry(key).or_insert(None);
+
+    if let Some(year) = year {
+        *span = match *span {
+            Some((first, last)) => Some((first.min(year), last.max(year))),
+            None => Some((year, year)),
+        };
+    }
+}
+
+/*
+ * Undirected connection, temporal at the publication year when it is known.
+ */
+fn add_year_connection(graph: &mut _Graph, from: String, to: String, weight: f32, year: Option<i32>) {
+    match year {
+        Some(year) => graph.create_temporal_connection(from, to, weight, Some(false), year as f64, None),
+        None => graph.create_connection(from, to, weight, Some(false)),
+    }
+}
+
+/*
+ * One connection per pair weighted by all the works it shares, spanning
+ * from the first to the last publication year of those works. Pairs whose
+ * works have no year get a connection without time.
+ */
+fn add_work_connection(graph: &mut _Graph, from: String, to: String, weight: f32, years: Option<(i32, i32)>) {
+    match years {
+        Some((first, last)) => graph.create_temporal_connection(from, to, weight, Some(false), first as f64, Some(last as f64)),
+        None => graph.create_connection(from, to, weight, Some(false)),
+    }
+}
+
 fn openalex_coauthorship(search: &str, api_key: &str, limit: Option<usize>, min_weight: Option<f32>, save_json_path: Option<&str>) -> _Graph {
     let mut graph = _Graph::default();
     let results = openalex_make_request_search(search, api_key, limit).expect("Request to OpenAlex failed!");
@@ -128,6 +165,7 @@ fn openalex_coauthorship(search: &str, api_key: &str, limit: Option<usize>, min_
     let mut unique_authors: HashSet<String> = HashSet::new();
 
     let mut edges_weight: HashMap<(String, String), f32> = HashMap::new();
+    let mut edges_years: HashMap<(String, String), Option<(i32, i32)>> = HashMap::new();
 
     // Max edge weight by author
     let mut max_edge_weight: HashMap<String, f32> = HashMap::new();
@@ -157,6 +195,7 @@ fn openalex_coauthorship(search: &str, api_key: &str, limit: Option<usize>, min_
                         (author_b.clone(), author_a.clone())
                     };
 
+                    extend_years(&mut edges_years, key.clone(), work.publication_year);
                     let counter = edges_weight.entry(key).or_insert(0.0);
                     *counter += 1.0;
 
@@ -190,11 +229,13 @@ fn openalex_coauthorship(search: &str, api_key: &str, limit: Option<usize>, min_
     }
 
     for ((from, to), weight) in edges_weight {
+        let years = edges_years[&(from.clone(), to.clone())];
+
         if min_weight.is_none() {
-            graph.create_connection(from, to, weight, Some(false));
+            add_work_connection(&mut graph, from, to, weight, years);
         }
         else if valid_nodes.contains(&from) && valid_nodes.contains(&to) && weight >= min_weight.unwrap() {
-            graph.create_connection(from, to, weight, Some(false));
+            add_work_connection(&mut graph, from, to, weight, years);
         }
     }
 
@@ -228,6 +269,7 @@ fn openalex_keyword_cooccurrence(search: &str, api_key: &str, limit: Option<usiz
     let mut unique_keywords: HashSet<String> = HashSet::new();
 
     let mut edges_weight: HashMap<(String, String), f32> = HashMap::new();
+    let mut edges_years: HashMap<(String, String), Option<(i32, i32)>> = HashMap::new();
 
     // Max edge weight by keyword
     let mut max_edge_weight: HashMap<String, f32> = HashMap::new();
@@ -256,6 +298,7 @@ fn openalex_keyword_cooccurrence(search: &str, api_key: &str, limit: Option<usiz
                         (keyword_b.clone(), keyword_a.clone())
                     };
 
+                    extend_years(&mut edges_years, key.clone(), work.publication_year);
                     let counter = edges_weight.entry(key).or_insert(0.0);
                     *counter += 1.0;
 
@@ -289,11 +332,13 @@ fn openalex_keyword_cooccurrence(search: &str, api_key: &str, limit: Option<usiz
     }
 
     for ((from, to), weight) in edges_weight {
+        let years = edges_years[&(from.clone(), to.clone())];
+
         if min_weight.is_none() {
-            graph.create_connection(from, to, weight, Some(false));
+            add_work_connection(&mut graph, from, to, weight, years);
         }
         else if valid_nodes.contains(&from) && valid_nodes.contains(&to) && weight >= min_weight.unwrap() {
-            graph.create_connection(from, to, weight, Some(false));
+            add_work_connection(&mut graph, from, to, weight, years);
         }
     }
 
@@ -383,6 +428,7 @@ fn openalex_cocitation(
     }
 
     let mut edges_weight: HashMap<(String, String), f32> = HashMap::new();
+    let mut edges_years: HashMap<(String, String), Option<(i32, i32)>> = HashMap::new();
     let mut max_edge_weight: HashMap<String, f32> = HashMap::new();
 
     for work in &results {
@@ -413,6 +459,7 @@ fn openalex_cocitation(
                         (entity_b.clone(), entity_a.clone())
                     };
 
+                    extend_years(&mut edges_years, key.clone(), work.publication_year);
                     let counter = edges_weight.entry(key).or_insert(0.0);
                     *counter += 1.0;
 
@@ -453,10 +500,12 @@ fn openalex_cocitation(
     }
 
     for ((from, to), weight) in edges_weight {
+        let years = edges_years[&(from.clone(), to.clone())];
+
         if min_weight.is_none() {
-            graph.create_connection(from, to, weight, Some(false));
+            add_work_connection(&mut graph, from, to, weight, years);
         } else if valid_nodes.contains(&from) && valid_nodes.contains(&to) && weight >= min_weight.unwrap() {
-            graph.create_connection(from, to, weight, Some(false));
+            add_work_connection(&mut graph, from, to, weight, years);
         }
     }
 
@@ -480,6 +529,118 @@ fn openalex_cocitation(
     return graph;
 }
 
+pub enum TwoModeType {
+    AuthorWork,
+    KeywordWork,
+}
+
+/*
+ * Two-mode (bipartite) graph: works on one side, labelled "<OpenAlex id>:
+ * <title>", authors or keywords on the other, with one undirected
+ * connection of weight 1 per occurrence.
+ * min_weight is the minimum number of works an author/keyword must appear in.
+ */
+fn openalex_two_mode(
+    search: &str,
+    api_key: &str,
+    limit: Option<usize>,
+    min_weight: Option<f32>,
+    mode_type: TwoModeType,
+    save_json_path: Option<&str>
+) -> _Graph {
+    let mut graph = _Graph::default();
+    let results = openalex_make_request_search(search, api_key, limit).expect("Request to OpenAlex failed!");
+
+    let start = Instant::now();
+
+    let mut work_entities: Vec<(String, Option<i32>, Vec<String>)> = Vec::new();
+    let mut entity_count: HashMap<String, f32> = HashMap::new();
+
+    for work in &results {
+        // Keyed by the OpenAlex id, so works with the same title stay apart and
+        // cannot be mistaken for an author or keyword with that name
+        let clean_id = work.id.split('/').last().unwrap_or(&work.id).to_string();
+        let work_name = match &work.display_name {
+            Some(title) => format!("{}: {}", clean_id, title),
+            None => clean_id,
+        };
+
+        let mut entities: Vec<String> = Vec::new();
+        match mode_type {
+            TwoModeType::AuthorWork => {
+                if let Some(authorships) = &work.authorships {
+                    for authorship in authorships {
+                        if let Some(author) = &authorship.author {
+                            if let Some(name) = &author.display_name {
+                                entities.push(name.clone());
+                            }
+                        }
+                    }
+                }
+            },
+            TwoModeType::KeywordWork => {
+                if let Some(keywords) = &work.keywords {
+                    for keyword in keywords {
+                        if let Some(keyword_name) = &keyword.display_name {
+                            entities.push(keyword_name.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        entities.sort();
+        entities.dedup();
+
+        for entity in &entities {
+            *entity_count.entry(entity.clone()).or_insert(0.) += 1.;
+        }
+
+        work_entities.push((work_name, work.publication_year, entities));
+    }
+
+    let mut placed: HashSet<String> = HashSet::new();
+
+    for (work_name, year, entities) in work_entities {
+        let valid_entities: Vec<String> = entities
+            .into_iter()
+            .filter(|e| min_weight.is_none() || entity_count[e] >= min_weight.unwrap())
+            .collect();
+
+        if valid_entities.is_empty() || !placed.insert(work_name.clone()) {
+            continue;
+        }
+
+        graph.add_node(work_name.clone());
+
+        for entity in valid_entities {
+            if placed.insert(entity.clone()) {
+                graph.add_node(entity.clone());
+            }
+            add_year_connection(&mut graph, work_name.clone(), entity, 1., year);
+        }
+    }
+
+    let duration = start.elapsed();
+
+    graph.build_time_ms = Some(duration.as_secs_f64() * 1000.0);
+
+    if let Some(path) = save_json_path {
+        match File::create(path) {
+            Ok(file) => {
+                if let Err(e) = serde_json::to_writer_pretty(file, &results) {
+                    eprintln!("Falha ao escrever o JSON no arquivo: {}", e);
+                }
+            }
+            Err(e) => {
+                eprintln!("Falha ao criar o arquivo JSON em {}: {}", path, e);
+            }
+        }
+    }
+
+    return graph;
+}
+
 pub fn dispatch_openalex_graph_creation(
     search: Option<&str>,
     author: Option<&str>,
@@ -535,7 +696,9 @@ pub fn dispatch_openalex_graph_creation(
         OpenAlexGraphType::Coauthorship => openalex_coauthorship(&params, api_key, limit, min_weight, save_json_path),
         OpenAlexGraphType::KeywordCooccurrence => openalex_keyword_cooccurrence(&params, api_key, limit, min_weight, save_json_path),
         OpenAlexGraphType::WorkCocitation => openalex_cocitation(&params, api_key, limit, min_weight, CocitationType::Work, save_json_path),
-        OpenAlexGraphType::AuthorCocitation => openalex_cocitation(&params, api_key, limit, min_weight, CocitationType::Author, save_json_path)
+        OpenAlexGraphType::AuthorCocitation => openalex_cocitation(&params, api_key, limit, min_weight, CocitationType::Author, save_json_path),
+        OpenAlexGraphType::AuthorWork => openalex_two_mode(&params, api_key, limit, min_weight, TwoModeType::AuthorWork, save_json_path),
+        OpenAlexGraphType::KeywordWork => openalex_two_mode(&params, api_key, limit, min_weight, TwoModeType::KeywordWork, save_json_path)
     };
 
     return graph;
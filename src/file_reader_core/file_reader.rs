@@ -123,7 +123,18 @@ pub struct JsonNode {
 pub struct JsonConnection {
     pub source: String,
     pub target: String,
-    pub weight: f32
+    pub weight: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<f64>
+}
+
+fn add_json_connection(graph: &mut _Graph, conn: JsonConnection, directed: bool) {
+    match conn.start {
+        Some(start) => graph.create_temporal_connection(conn.source, conn.target, conn.weight, Some(directed), start, conn.end),
+        None => graph.create_connection(conn.source, conn.target, conn.weight, Some(directed)),
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -150,13 +161,13 @@ pub fn read_json_file(file_path: &str) -> Result<_Graph, Error> {
     }
     if !json_graph.edges.is_none() {
         for edge in json_graph.edges.unwrap() {
-            new_graph.create_connection(edge.source, edge.target, edge.weight, Some(false));
+            add_json_connection(&mut new_graph, edge, false);
         }
     }
 
     if !json_graph.arcs.is_none() {
         for arc in json_graph.arcs.unwrap() {
-            new_graph.create_connection(arc.source, arc.target, arc.weight, Some(true));
+            add_json_connection(&mut new_graph, arc, true);
         }
     }
 
@@ -114,7 +114,9 @@ pub fn write_json_file(path: &str, nodes: Vec<Rc<RefCell<_Node>>>) -> Result<(),
                 let json_conn = JsonConnection {
                     source: node.label.clone(),
                     target: connected_node.label.clone(),
-                    weight: conn.weight
+                    weight: conn.weight,
+                    start: conn.time.map(|t| t.0),
+                    end: conn.time.map(|t| t.1)
                 };
 
                 if conn.directed {
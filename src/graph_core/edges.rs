@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::graph_core::graph::_Graph;
+use crate::graph_core::node::_Node;
+
+/*
+ * One connection of the graph. 'from' and 'to' are positions inside
+ * graph.nodes; the connection is stored on the 'from' node. 'time' is the
+ * (start, end) interval of temporal connections.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeRef {
+    pub from: usize,
+    pub to: usize,
+    pub weight: f32,
+    pub directed: bool,
+    pub time: Option<(f64, f64)>
+}
+
+/*
+ * Position of each node inside graph.nodes, keyed by the node itself.
+ */
+pub type NodePositions = HashMap<*const RefCell<_Node>, usize>;
+
+/*
+ * Iterator over every connection of a graph, in the order they are stored
+ * (node by node, then connection by connection). Only the node positions
+ * are looked up once when it is created; nothing is allocated per
+ * connection. Connections to nodes that no longer exist are skipped.
+ */
+pub struct Edges<'a> {
+    graph: &'a _Graph,
+    positions: Rc<NodePositions>,
+    node: usize,
+    connection: usize
+}
+
+impl<'a> Edges<'a> {
+    pub fn new(graph: &'a _Graph) -> Edges<'a> {
+        let positions: NodePositions = graph.nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (Rc::as_ptr(n), i))
+            .collect();
+
+        return Edges { graph: graph, positions: Rc::new(positions), node: 0, connection: 0 };
+    }
+
+    /*
+     * Goes on with an iteration stopped at 'cursor' (see cursor()), reusing
+     * the node positions it looked up, for callers that cannot keep the
+     * graph borrowed between two steps.
+     */
+    pub fn resume(graph: &'a _Graph, positions: Rc<NodePositions>, cursor: (usize, usize)) -> Edges<'a> {
+        return Edges { graph: graph, positions: positions, node: cursor.0, connection: cursor.1 };
+    }
+
+    pub fn positions(&self) -> Rc<NodePositions> {
+        return Rc::clone(&self.positions);
+    }
+
+    /*
+     * (node, connection) of the next connection to look at.
+     */
+    pub fn cursor(&self) -> (usize, usize) {
+        return (self.node, self.connection);
+    }
+}
+
+impl<'a> Iterator for Edges<'a> {
+    type Item = EdgeRef;
+
+    fn next(&mut self) -> Option<EdgeRef> {
+        let graph = self.graph;
+
+        while self.node < graph.nodes.len() {
+            let node = graph.nodes[self.node].borrow();
+
+            while self.connection < node.connections.len() {
+                let conn = &node.connections[self.connection];
+                self.connection += 1;
+
+                if conn.node.strong_count() == 0 {
+                    continue;
+                }
+
+                if let Some(to) = self.positions.get(&conn.node.as_ptr()) {
+                    return Some(EdgeRef { from: self.node, to: *to, weight: conn.weight, directed: conn.directed, time: conn.time });
+                }
+            }
+
+            self.node += 1;
+            self.connection = 0;
+        }
+
+        return None;
+    }
+}
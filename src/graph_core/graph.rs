@@ -1,11 +1,12 @@
 use crate::layout::style::GraphStyle;
 use crate::{HtmlWriter, Node, Writeable};
 use crate::graph_core::node::{self, _Node};
+use crate::graph_core::edges::Edges;
 use crate::file_writer_core::file_writer::{write_json_file, write_net_file};
 use std::f64;
 use std::hash::Hash;
 use std::time::Instant;
-use std::{collections::HashMap, collections::HashSet, collections::VecDeque};
+use std::{collections::HashMap, collections::HashSet};
 use std::cell::RefCell;
 use std::rc::{Rc};
 use crate::svg_creation::svg_creation::Svg;
@@ -13,6 +14,30 @@ use crate::layout::layout::Layout;
 use crate::file_reader_core::file_reader::{read_json_file, read_net_file};
 use crate::external_apis::core::{OpenAlexGraphType};
 use crate::external_apis::openalex::dispatch_openalex_graph_creation;
+use crate::algorithms::dag::{self, CycleError};
+use crate::algorithms::main_path::{self, TraversalWeight};
+use crate::algorithms::bipartite::{self, NotBipartiteError, ProjectionMethod};
+use crate::algorithms::isomorphism::{self, EdgeMatch, NodeMatch};
+use crate::algorithms::motifs::{self, MotifCensus};
+use crate::algorithms::null_models::NullModel;
+use crate::algorithms::random_walks;
+use crate::algorithms::link_prediction::{self, LinkPredictor};
+use crate::algorithms::similarity::{self, SimilarityMethod};
+use crate::algorithms::connectivity;
+use crate::algorithms::cliques;
+use crate::algorithms::paths::{self, InvalidLengthError, SimplePaths, WeightFn, WeightInterpretation};
+use crate::algorithms::robustness::{self, RemovalStrategy, RemovalTarget, RobustnessResult};
+use crate::algorithms::rewiring::{self, Significance};
+use crate::algorithms::sampling;
+use crate::algorithms::quotient::{self, EdgeAggregation, GroupNameError};
+use crate::algorithms::node_metrics::{self, NodeMetrics, UnknownMetricError};
+use crate::algorithms::summary::{self, GraphSummary};
+use crate::algorithms::power_law::{self, DegreeType, NotEnoughDataError, PowerLawFit};
+use crate::algorithms::backbone::{self, BackboneMethod};
+use crate::algorithms::traversal::{self, DiscoveryOrder, TraversalVisitor};
+use crate::algorithms::temporal;
+use crate::simulations::diffusion::{self, SimulationResult};
+use crate::simulations::influence::{self, CascadeModel, SeedSelection};
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -74,17 +99,6 @@ fn create_node_hashmap(nodes: &Vec<Rc<RefCell<_Node>>>, start_index: usize)  ->
     return node_hash;
 }
 
-fn invert_node_hashmap(node_hashmap: HashMap<usize, String>) -> HashMap<String, usize> {
-    let mut inverted_node_hash: HashMap<String, usize> = HashMap::new();
-
-    for (key, value) in node_hashmap.iter() {
-        inverted_node_hash.insert(value.clone(), *key);
-    }
-
-    return inverted_node_hash;
-}
-
-
 impl _Graph {
     pub fn add_node(&mut self, label: String) {
 
@@ -152,6 +166,27 @@ impl _Graph {
         from_node.borrow_mut().add_connection(to_node_ref, weight, directed);
     }
 
+    /*
+     * Connection that only exists at 'start', or during [start, end] when an
+     * end is given.
+     */
+    pub fn create_temporal_connection(&mut self, from: String, to: String, weight: f32, directed: Option<bool>, start: f64, end: Option<f64>) {
+        let end = end.unwrap_or(start);
+        if end < start {
+            panic!("Connection ends before it starts");
+        }
+
+        let from_node = self._node_label_hack.get(&from)
+                .expect("Node 'from' not found")
+                .clone();
+
+        let to_node = self._node_label_hack.get(&to)
+            .expect("Node 'to' not found")
+            .clone();
+
+        from_node.borrow_mut().add_temporal_connection(Rc::clone(&to_node), weight, directed, start, end);
+    }
+
     pub fn node_by_label(&self, label: &str) -> Option<Rc<RefCell<_Node>>> {
         for n in &self.nodes {
             if n.borrow().label == label {
@@ -205,42 +240,24 @@ impl _Graph {
         return all_connections;
     }
 
+    /*
+     * Every connection of the graph as an EdgeRef, without building any
+     * intermediate collection.
+     */
+    pub fn edges(&self) -> Edges<'_> {
+        return Edges::new(self);
+    }
+
     pub fn generate_adjacency_matrix(&mut self) -> Vec<Vec<f32>> {
         let matrix_size = self.nodes.len();
         let mut adj_matrix: Vec<Vec<f32>> = vec![vec![0.; matrix_size]; matrix_size];
-        let node_hash = invert_node_hashmap(create_node_hashmap(&self.nodes, 0));
-        let connections = self.get_connections(None, None, false);
-
-        for conn in connections {
-
-            let from_label = match &conn["from"] {
-                ConnectionProperty::From(s) => s,
-                _ => unreachable!(),
-            };
 
-            let to_label = match &conn["to"] {
-                ConnectionProperty::To(s) => s,
-                _ => unreachable!(),
-            };
+        for edge in self.edges() {
+            adj_matrix[edge.from][edge.to] = edge.weight;
 
-            let weight = match &conn["weight"] {
-                ConnectionProperty::Weight(w) => w,
-                _ => unreachable!(),
-            };
-
-            let directed = match &conn["directed"] {
-                ConnectionProperty::Directed(d) => d,
-                _ => unreachable!(),
-            };
-
-            let i = node_hash[from_label];
-            let j = node_hash[to_label];
-            adj_matrix[i][j] = *weight;
-
-            if !*directed {
-                adj_matrix[j][i] = *weight;
+            if !edge.directed {
+                adj_matrix[edge.to][edge.from] = edge.weight;
             }
-
         }
 
         return adj_matrix;
@@ -248,15 +265,8 @@ impl _Graph {
     }
 
     pub fn get_total_weight(&mut self) -> f32 {
-        let total_weight: f32 = self.get_connections(None, None, false)
-            .iter()
-            .map(|conn| {
-                if let ConnectionProperty::Weight(w) = conn["weight"] {
-                    w
-                } else {
-                    0.
-                }
-            })
+        let total_weight: f32 = self.edges()
+            .map(|edge| edge.weight)
             .sum();
 
         return total_weight;
@@ -267,7 +277,7 @@ impl _Graph {
     }
 
     pub fn get_edge_count(&mut self) -> usize {
-        return self.get_connections(None, None, false).len();
+        return self.edges().count();
     }
 
     pub fn get_density(&mut self, directed: Option<bool>) -> f32 {
@@ -286,11 +296,11 @@ impl _Graph {
     }
 
     pub fn get_mean_weight(&mut self) -> f32 {
-        return self.get_total_weight() / self.get_connections(None, None, false).len() as f32;
+        return self.get_total_weight() / self.get_edge_count() as f32;
     }
 
     pub fn compute_degrees(&mut self, node_label: &str) -> HashMap<String, i32> {
-        let connections = self.get_connections(None, None, false);
+        let position = self.nodes.iter().position(|n| n.borrow().label == node_label);
 
         let mut degrees: HashMap<String, i32> = HashMap::new();
         degrees.insert("in_degree".to_string(), 0);
@@ -298,29 +308,17 @@ impl _Graph {
         degrees.insert("total_degree".to_string(), 0);
         degrees.insert("undirected_degree".to_string(), 0);
 
-        for conn in  connections {
-            let directed = match &conn["directed"] {
-                ConnectionProperty::Directed(d) => d,
-                _ => unreachable!()
-            };
-
-            let from = match &conn["from"] {
-                ConnectionProperty::From(f) => f,
-                _ => unreachable!()
-            };
+        for edge in self.edges() {
+            let from = Some(edge.from) == position;
+            let to = Some(edge.to) == position;
 
-            let to = match &conn["to"] {
-                ConnectionProperty::To(f) => f,
-                _ => unreachable!()
-            };
-
-            if *directed {
-                if from == node_label {
+            if edge.directed {
+                if from {
                     *degrees.entry("out_degree".to_string()).or_insert(0) += 1;
                     *degrees.entry("total_degree".to_string()).or_insert(0) += 1;
                 }
 
-                if to == node_label {
+                if to {
                     *degrees.entry("in_degree".to_string()).or_insert(0) += 1;
                     *degrees.entry("total_degree".to_string()).or_insert(0) += 1;
                 }
@@ -328,7 +326,7 @@ impl _Graph {
                 continue;
             }
 
-            if to == node_label || from == node_label {
+            if to || from {
                 *degrees.entry("undirected_degree".to_string()).or_insert(0) += 1;
             }
         }
@@ -339,14 +337,20 @@ impl _Graph {
     pub fn get_all_nodes_degrees(&mut self) -> HashMap<String, HashMap<String, i32>> {
         let mut degree_hash: HashMap<String, HashMap<String, i32>> = HashMap::new();
 
-        let labels: Vec<String> = self.nodes
-                .iter()
-                .map(|n| n.borrow().label.clone())
-                .collect();
+        let table = self.node_metrics(&vec!["degree".to_string()]).unwrap();
+        let in_degree = table.in_degree.unwrap();
+        let out_degree = table.out_degree.unwrap();
+        let total_degree = table.total_degree.unwrap();
+        let undirected_degree = table.undirected_degree.unwrap();
+
+        for (i, label) in table.label.into_iter().enumerate() {
+            let mut degrees: HashMap<String, i32> = HashMap::new();
+            degrees.insert("in_degree".to_string(), in_degree[i]);
+            degrees.insert("out_degree".to_string(), out_degree[i]);
+            degrees.insert("total_degree".to_string(), total_degree[i]);
+            degrees.insert("undirected_degree".to_string(), undirected_degree[i]);
 
-        for label in labels {
-            let degree = self.compute_degrees(&label);
-            degree_hash.insert(label,degree);
+            degree_hash.insert(label, degrees);
         }
 
         return degree_hash;
@@ -370,7 +374,7 @@ impl _Graph {
     }
 
     pub fn get_node_strength(&mut self, node_label: &str) -> HashMap<&str, f32> {
-        let connections = self.get_connections(None, None, false);
+        let position = self.nodes.iter().position(|n| n.borrow().label == node_label);
 
         let mut strengths: HashMap<&str, f32> = HashMap::new();
 
@@ -378,44 +382,20 @@ impl _Graph {
         strengths.insert("in_strength", 0.);
         strengths.insert("total_strength", 0.);
 
-        for conn in connections {
+        for edge in self.edges() {
+            if Some(edge.from) == position {
+                *strengths.get_mut("out_strength").unwrap() += edge.weight;
 
-
-
-            let from_label = match &conn["from"] {
-                ConnectionProperty::From(s) => s,
-                _ => unreachable!(),
-            };
-
-
-            let to_label = match &conn["to"] {
-                ConnectionProperty::To(s) => s,
-                _ => unreachable!(),
-            };
-
-            let weight = match &conn["weight"] {
-                ConnectionProperty::Weight(w) => w,
-                _ => unreachable!(),
-            };
-
-            let directed = match &conn["directed"] {
-                ConnectionProperty::Directed(d) => d,
-                _ => unreachable!(),
-            };
-
-            if from_label == node_label {
-                *strengths.get_mut("out_strength").unwrap() += *weight;
-
-                if !*directed {
-                    *strengths.get_mut("in_strength").unwrap() += *weight;
+                if !edge.directed {
+                    *strengths.get_mut("in_strength").unwrap() += edge.weight;
                 }
             }
 
-            if to_label == node_label {
-                *strengths.get_mut("in_strength").unwrap() += *weight;
+            if Some(edge.to) == position {
+                *strengths.get_mut("in_strength").unwrap() += edge.weight;
 
-                if !*directed {
-                    *strengths.get_mut("out_strength").unwrap() += *weight;
+                if !edge.directed {
+                    *strengths.get_mut("out_strength").unwrap() += edge.weight;
                 }
             }
 
@@ -446,15 +426,7 @@ impl _Graph {
     }
 
     pub fn get_degree_distribution(&mut self) -> HashMap<&str, HashMap<i32 ,f32>>{
-        let mut computed_degrees: Vec<HashMap<String, i32>> = Vec::new();
-
-        let mut nodes = self.nodes.clone();
-
-        for n in &mut nodes {
-            let mut node = n.borrow();
-
-            computed_degrees.push(self.compute_degrees(&node.label));
-        }
+        let computed_degrees: Vec<HashMap<String, i32>> = self.get_all_nodes_degrees().into_values().collect();
 
         let node_count = self.get_node_count();
         let mut count_in_degree: HashMap<i32, i32> = HashMap::new();
@@ -624,128 +596,384 @@ impl _Graph {
         return result;
     }
 
+    pub fn describe(&self) -> GraphSummary {
+        return summary::describe(self);
+    }
+
+    pub fn power_law_fit(
+        &self,
+        degree: DegreeType,
+        xmin: Option<usize>,
+        n_bootstrap: usize,
+        seed: Option<u64>
+    ) -> Result<PowerLawFit, NotEnoughDataError> {
+        return power_law::degree_power_law(self, &degree, xmin, n_bootstrap, seed);
+    }
+
+    pub fn node_metrics(&self, metrics: &Vec<String>) -> Result<NodeMetrics, UnknownMetricError> {
+        return node_metrics::node_metrics(self, metrics);
+    }
+
+    pub fn triad_census(&self) -> HashMap<String, f64> {
+        return motifs::triad_census(self);
+    }
+
+    pub fn motif_census(&self, motif_size: usize) -> HashMap<String, f64> {
+        return motifs::motif_census(self, motif_size);
+    }
+
+    pub fn motif_z_scores(&self, census: MotifCensus, n_samples: usize, null_model: NullModel, seed: Option<u64>) -> HashMap<String, f64> {
+        return motifs::motif_z_scores(self, &census, n_samples, &null_model, seed);
+    }
+
     /*
-     * This method has almost no purpouse right now, but the idea
-     * is that it'll be able to get a function that will be applied to each
-     * node in the future (same thing to bfs)
+     * Labels in depth-first discovery order. Use dfs_visit to run custom
+     * code on each traversal event.
      */
     pub fn dfs(&mut self, start_node_label: &str) -> Vec<String> {
-        let mut final_order: Vec<String> = Vec::new();
+        let mut visitor = DiscoveryOrder::default();
+        traversal::dfs_visit(self, start_node_label, &mut visitor, None);
 
-        let mut visited: HashSet<String> = HashSet::new();
+        return visitor.order;
+    }
 
-        let mut stack: Vec<Rc<RefCell<_Node>>> = Vec::new();
-        let starting_node: Rc<RefCell<_Node>> = self.node_by_label(start_node_label).expect("Error: Initial node not found");
+    pub fn bfs(&mut self, start_node_label: &str) -> Vec<String> {
+        let mut visitor = DiscoveryOrder::default();
+        traversal::bfs_visit(self, start_node_label, &mut visitor, None);
 
-        stack.push(starting_node);
+        return visitor.order;
+    }
 
-        while let Some(n) = stack.pop() {
-            let node = n.borrow();
+    pub fn dfs_visit(&self, start_node_label: &str, visitor: &mut dyn TraversalVisitor, max_depth: Option<usize>) {
+        traversal::dfs_visit(self, start_node_label, visitor, max_depth);
+    }
 
-            if !visited.insert(node.label.clone()) {
-                continue;
-            }
+    pub fn bfs_visit(&self, start_node_label: &str, visitor: &mut dyn TraversalVisitor, max_depth: Option<usize>) {
+        traversal::bfs_visit(self, start_node_label, visitor, max_depth);
+    }
 
-            final_order.push(node.label.clone());
+    pub fn dijkstra(
+        &self,
+        start_node_label: &str,
+        weights: WeightInterpretation,
+        weight_fn: Option<WeightFn>
+    ) -> Result<HashMap<String, f64>, InvalidLengthError> {
+        return paths::dijkstra(self, start_node_label, &weights, weight_fn);
+    }
 
-            for conn in node.connections.iter().rev() {
-                if let Some(rc_node) = conn.node.upgrade() {
-                    let conn_node = rc_node.borrow();
+    pub fn k_shortest_paths(
+        &self,
+        source_label: &str,
+        target_label: &str,
+        k: usize,
+        weights: WeightInterpretation,
+        weight_fn: Option<WeightFn>
+    ) -> Result<Vec<(Vec<String>, f64)>, InvalidLengthError> {
+        return paths::k_shortest_paths(self, source_label, target_label, k, &weights, weight_fn);
+    }
 
-                    if !visited.contains(&conn_node.label) {
-                        stack.push(Rc::clone(&rc_node));
-                    }
-                }
-            }
-        }
+    pub fn all_simple_paths(&self, source_label: &str, target_label: &str, cutoff: Option<usize>) -> SimplePaths {
+        return paths::all_simple_paths(self, source_label, target_label, cutoff);
+    }
 
-        return final_order;
+    pub fn is_dag(&self) -> bool {
+        return dag::is_dag(self);
+    }
 
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        return dag::find_cycle(self);
     }
 
-    pub fn bfs(&mut self, start_node_label: &str) -> Vec<String> {
-        let mut final_order: Vec<String> = Vec::new();
+    pub fn simple_cycles(&self) -> Vec<Vec<String>> {
+        return dag::simple_cycles(self);
+    }
 
-        let mut q: VecDeque<Rc<RefCell<_Node>>> = VecDeque::new();
-        let mut visited: HashSet<String> = HashSet::new();
+    pub fn topological_sort(&self) -> Result<Vec<String>, CycleError> {
+        return dag::topological_sort(self);
+    }
 
-        let starting_node: Rc<RefCell<_Node>> = self.node_by_label(start_node_label).expect("Error: Initial node not found");
+    pub fn dag_longest_path(
+        &self,
+        weights: WeightInterpretation,
+        weight_fn: Option<WeightFn>
+    ) -> Result<Vec<String>, CycleError> {
+        return dag::dag_longest_path(self, &weights, weight_fn);
+    }
 
+    pub fn ancestors(&self, node_label: &str) -> HashSet<String> {
+        return dag::ancestors(self, node_label);
+    }
 
-        visited.insert(start_node_label.to_string());
-        q.push_back(starting_node);
+    pub fn descendants(&self, node_label: &str) -> HashSet<String> {
+        return dag::descendants(self, node_label);
+    }
 
-        while let Some(n) = q.pop_front() {
-            let node = n.borrow();
+    pub fn traversal_weights(&self, weight_type: TraversalWeight) -> Result<Vec<(String, String, f64)>, CycleError> {
+        return main_path::traversal_weights(self, &weight_type);
+    }
 
-            final_order.push(node.label.clone());
+    pub fn local_main_path(&self, weight_type: TraversalWeight) -> Result<_Graph, CycleError> {
+        return main_path::local_main_path(self, &weight_type);
+    }
 
-            for conn in &node.connections {
-                if let Some(rc_node) = conn.node.upgrade() {
-                    let conn_node = rc_node.borrow();
+    pub fn global_main_path(&self, weight_type: TraversalWeight) -> Result<_Graph, CycleError> {
+        return main_path::global_main_path(self, &weight_type);
+    }
 
-                    if visited.insert(conn_node.label.clone()) {
-                        q.push_back(Rc::clone(&rc_node));
-                    }
-                }
-            }
-        }
+    pub fn key_route_main_path(&self, key_routes: usize, weight_type: TraversalWeight) -> Result<_Graph, CycleError> {
+        return main_path::key_route_main_path(self, key_routes, &weight_type);
+    }
 
+    pub fn is_bipartite(&self) -> bool {
+        return bipartite::is_bipartite(self);
+    }
 
-        return final_order;
+    pub fn bipartite_sets(&self) -> Result<(Vec<String>, Vec<String>), NotBipartiteError> {
+        return bipartite::bipartite_sets(self);
     }
 
-    pub fn dijkstra(&mut self, start_node_label: &str) -> HashMap<String, f64>{
-        let size = self.nodes.len();
-        let node_ref = self.node_by_label(start_node_label).expect("Node not found");
-        let mut distances: HashMap<String, f64> = HashMap::new();
+    pub fn bipartite_projection(&self, node_labels: &Vec<String>, method: ProjectionMethod) -> _Graph {
+        return bipartite::bipartite_projection(self, node_labels, &method);
+    }
 
-        for i in 0..size {
-            let lbl = self.nodes[i].borrow().label.clone();
-            distances.insert(lbl, f64::INFINITY);
-        }
+    pub fn maximum_matching(&self) -> Result<Vec<(String, String)>, NotBipartiteError> {
+        return bipartite::maximum_matching(self);
+    }
 
-        distances.insert(node_ref.borrow().label.clone(), 0.);
+    pub fn is_isomorphic(&self, other: &_Graph, node_match: Option<NodeMatch>, edge_match: Option<EdgeMatch>) -> bool {
+        return isomorphism::is_isomorphic(self, other, node_match, edge_match);
+    }
 
-        let mut visited = vec![false; size];
-        let adj_matrix = self.generate_adjacency_matrix();
+    pub fn subgraph_isomorphisms(
+        &self,
+        pattern: &_Graph,
+        induced: bool,
+        node_match: Option<NodeMatch>,
+        edge_match: Option<EdgeMatch>
+    ) -> Vec<HashMap<String, String>> {
+        return isomorphism::subgraph_isomorphisms(self, pattern, induced, node_match, edge_match);
+    }
 
-        for _ in 0..size {
-            let mut min_distance = f64::INFINITY;
-            let mut u: Option<usize> = None;
+    pub fn random_walks(&self, walk_length: usize, walks_per_node: usize, weighted: bool, seed: Option<u64>) -> Vec<Vec<String>> {
+        return random_walks::random_walks(self, walk_length, walks_per_node, weighted, seed);
+    }
 
-            for i in 0..size {
-                if !visited[i] && distances[&self.nodes[i].borrow().label] < min_distance {
-                    min_distance = distances[&self.nodes[i].borrow().label];
-                    u = Some(i);
-                }
-            }
+    pub fn node2vec_walks(
+        &self,
+        walk_length: usize,
+        walks_per_node: usize,
+        p: f64,
+        q: f64,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> Vec<Vec<String>> {
+        return random_walks::node2vec_walks(self, walk_length, walks_per_node, p, q, weighted, seed);
+    }
 
-            if u == None {
-                break;
-            }
-            let u: usize = u.unwrap();
-            visited[u] = true;
+    pub fn link_prediction(
+        &self,
+        method: LinkPredictor,
+        candidates: Option<&Vec<(String, String)>>,
+        top_k: Option<usize>,
+        beta: f64
+    ) -> Vec<(String, String, f64)> {
+        return link_prediction::link_prediction(self, &method, candidates, top_k, beta);
+    }
 
+    pub fn similarity_matrix(&self, method: SimilarityMethod, iterations: usize, decay: f64) -> Vec<Vec<f64>> {
+        return similarity::similarity_matrix(self, &method, iterations, decay);
+    }
 
-            for v in 0..size {
-                if adj_matrix[u][v] != 0. && !visited[v] {
-                    let alt = distances[&self.nodes[u].borrow().label] as f32 + adj_matrix[u][v];
-                    if alt < distances[&self.nodes[v].borrow().label] as f32{
-                        distances.insert(self.nodes[v].borrow().label.clone(), alt as f64);
-                    }
-                }
-            }
-        }
+    pub fn most_similar(&self, k: usize, method: SimilarityMethod, iterations: usize, decay: f64) -> HashMap<String, Vec<(String, f64)>> {
+        return similarity::most_similar(self, &method, k, iterations, decay);
+    }
+
+    pub fn articulation_points(&self) -> HashSet<String> {
+        return connectivity::articulation_points(self);
+    }
 
-        return distances;
+    pub fn bridges(&self) -> Vec<(String, String)> {
+        return connectivity::bridges(self);
+    }
+
+    pub fn biconnected_components(&self) -> Vec<HashSet<String>> {
+        return connectivity::biconnected_components(self);
+    }
+
+    pub fn biconnected_component_edges(&self) -> Vec<Vec<(String, String)>> {
+        return connectivity::biconnected_component_edges(self);
+    }
+
+    pub fn maximal_cliques(&self) -> Vec<Vec<String>> {
+        return cliques::maximal_cliques(self);
+    }
+
+    pub fn clique_number(&self) -> usize {
+        return cliques::clique_number(self);
+    }
+
+    pub fn node_clique_number(&self) -> HashMap<String, usize> {
+        return cliques::node_clique_number(self);
+    }
+
+    pub fn k_clique_communities(&self, k: usize) -> Vec<HashSet<String>> {
+        return cliques::k_clique_communities(self, k);
+    }
+
+    pub fn robustness(
+        &self,
+        target: RemovalTarget,
+        strategy: RemovalStrategy,
+        adaptive: bool,
+        max_removals: Option<usize>,
+        seed: Option<u64>,
+        weights: WeightInterpretation,
+        weight_fn: Option<WeightFn>
+    ) -> Result<RobustnessResult, InvalidLengthError> {
+        return robustness::robustness(self, &target, &strategy, adaptive, max_removals, seed, &weights, weight_fn);
+    }
+
+    pub fn double_edge_swap(&self, n_swaps: Option<usize>, seed: Option<u64>) -> _Graph {
+        return rewiring::double_edge_swap_graph(self, n_swaps, seed);
+    }
+
+    pub fn directed_edge_swap(&self, n_swaps: Option<usize>, seed: Option<u64>) -> _Graph {
+        return rewiring::directed_edge_swap_graph(self, n_swaps, seed);
+    }
+
+    pub fn significance(&self, metric_fn: &mut dyn FnMut(_Graph) -> f64, n_samples: usize, seed: Option<u64>) -> Significance {
+        return rewiring::significance(self, metric_fn, n_samples, seed);
+    }
+
+    pub fn random_node_sample(&self, target_size: usize, seed: Option<u64>) -> _Graph {
+        return sampling::random_node_sample(self, target_size, seed);
+    }
+
+    pub fn random_edge_sample(&self, target_size: usize, seed: Option<u64>) -> _Graph {
+        return sampling::random_edge_sample(self, target_size, seed);
+    }
+
+    pub fn snowball_sample(&self, target_size: usize, depth: Option<usize>, start_node: Option<&str>, seed: Option<u64>) -> _Graph {
+        return sampling::snowball_sample(self, target_size, depth, start_node, seed);
+    }
+
+    pub fn random_walk_sample(&self, target_size: usize, restart_probability: f64, seed: Option<u64>) -> _Graph {
+        return sampling::random_walk_sample(self, target_size, restart_probability, seed);
+    }
+
+    pub fn forest_fire_sample(&self, target_size: usize, burn_probability: f64, seed: Option<u64>) -> _Graph {
+        return sampling::forest_fire_sample(self, target_size, burn_probability, seed);
+    }
+
+    pub fn quotient_graph(
+        &self,
+        groups: &HashMap<String, String>,
+        aggregation: EdgeAggregation,
+        self_loops: bool
+    ) -> Result<_Graph, GroupNameError> {
+        return quotient::quotient_graph(self, groups, &aggregation, self_loops);
+    }
+
+    pub fn backbone(
+        &self,
+        method: BackboneMethod,
+        threshold: Option<f64>
+    ) -> Result<(_Graph, Vec<(String, String, f64)>), InvalidLengthError> {
+        return backbone::backbone(self, &method, threshold);
+    }
+
+    pub fn time_span(&self) -> Option<(f64, f64)> {
+        return temporal::time_span(self);
+    }
+
+    pub fn snapshot(&self, time: f64) -> _Graph {
+        return temporal::snapshot(self, time);
+    }
+
+    pub fn window(&self, start: f64, end: f64) -> _Graph {
+        return temporal::window(self, start, end);
+    }
+
+    pub fn sliding_windows(
+        &self,
+        width: f64,
+        step: Option<f64>,
+        start: Option<f64>,
+        end: Option<f64>
+    ) -> impl Iterator<Item = (f64, f64, _Graph)> + '_ {
+        return temporal::sliding_windows(self, width, step, start, end);
+    }
+
+    pub fn temporal_reachability(&self, source_label: &str, start_time: Option<f64>) -> HashMap<String, f64> {
+        return temporal::temporal_reachability(self, source_label, start_time);
+    }
+
+    pub fn time_respecting_path(&self, source_label: &str, target_label: &str, start_time: Option<f64>) -> Option<Vec<(String, String, f64)>> {
+        return temporal::time_respecting_path(self, source_label, target_label, start_time);
+    }
+
+    pub fn sir(
+        &self,
+        seed_nodes: &Vec<String>,
+        beta: f64,
+        gamma: f64,
+        max_steps: usize,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> SimulationResult {
+        return diffusion::sir(self, seed_nodes, beta, gamma, max_steps, weighted, seed);
+    }
+
+    pub fn sis(
+        &self,
+        seed_nodes: &Vec<String>,
+        beta: f64,
+        gamma: f64,
+        max_steps: usize,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> SimulationResult {
+        return diffusion::sis(self, seed_nodes, beta, gamma, max_steps, weighted, seed);
+    }
+
+    pub fn independent_cascade(
+        &self,
+        seed_nodes: &Vec<String>,
+        probability: f64,
+        weighted: bool,
+        max_steps: Option<usize>,
+        seed: Option<u64>
+    ) -> SimulationResult {
+        return diffusion::independent_cascade(self, seed_nodes, probability, weighted, max_steps, seed);
+    }
+
+    pub fn linear_threshold(
+        &self,
+        seed_nodes: &Vec<String>,
+        threshold: Option<f64>,
+        weighted: bool,
+        max_steps: Option<usize>,
+        seed: Option<u64>
+    ) -> SimulationResult {
+        return diffusion::linear_threshold(self, seed_nodes, threshold, weighted, max_steps, seed);
+    }
 
+    pub fn influence_maximization(
+        &self,
+        k: usize,
+        model: CascadeModel,
+        method: SeedSelection,
+        n_simulations: usize,
+        probability: f64,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> (Vec<String>, f64) {
+        return influence::influence_maximization(self, k, &model, &method, n_simulations, probability, weighted, seed);
     }
 
     pub fn output_svg(&mut self, layout: Layout, override_positions: bool, style: GraphStyle) -> String {
         let mut svg: Svg = Svg::new();
-        let connections = self.get_connections(None, None, false);
-        let svg_string = svg.get_svg(&self.nodes, &connections, layout, self.positions_set, override_positions, style);
+        let svg_string = svg.get_svg(self, layout, self.positions_set, override_positions, style);
         return svg_string;
     }
 
@@ -1,2 +1,3 @@
 pub mod node;
-pub mod graph;
\ No newline at end of file
+pub mod graph;
+pub mod edges;
@@ -5,7 +5,8 @@ use std::cell::RefCell;
 pub struct Connection {
     pub node: Weak<RefCell<_Node>>,
     pub weight: f32,
-    pub directed: bool
+    pub directed: bool,
+    pub time: Option<(f64, f64)> // (start, end), start == end for a single timestamp
 }
 
 
@@ -23,7 +24,20 @@ impl _Node {
         let new_conn = Connection {
             node: Rc::downgrade(&node),
             weight: weight,
-            directed: directed
+            directed: directed,
+            time: None
+        };
+
+        self.connections.push(new_conn);
+    }
+
+    pub fn add_temporal_connection(&mut self, node: Rc<RefCell<_Node>>, weight: f32, directed: Option<bool>, start: f64, end: f64) {
+        let directed = directed.unwrap_or(false);
+        let new_conn = Connection {
+            node: Rc::downgrade(&node),
+            weight: weight,
+            directed: directed,
+            time: Some((start, end))
         };
 
         self.connections.push(new_conn);
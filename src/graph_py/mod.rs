@@ -1,2 +1,5 @@
 pub mod py_graph;
-pub mod py_node;
\ No newline at end of file
+pub mod py_node;
+pub mod py_paths;
+pub mod py_edges;
+pub mod py_temporal;
@@ -0,0 +1,54 @@
+use pyo3::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::graph_core::graph::_Graph;
+use crate::graph_core::edges::{Edges, NodePositions};
+use pyo3_stub_gen::derive::gen_stub_pyclass;
+
+/*
+ * Lazy iterator over the connections of a graph as
+ * (from, to, weight, directed) tuples. It keeps where the iteration
+ * stopped and reads the graph at each step, so nothing is copied up front.
+ */
+#[gen_stub_pyclass]
+#[pyclass(unsendable, module="netfog")]
+pub struct EdgeIterator {
+    pub graph: Rc<RefCell<_Graph>>,
+    pub positions: Rc<NodePositions>,
+    pub cursor: (usize, usize)
+}
+
+impl EdgeIterator {
+    pub fn new(graph: &Rc<RefCell<_Graph>>) -> EdgeIterator {
+        let positions = graph.borrow().edges().positions();
+
+        return EdgeIterator { graph: Rc::clone(graph), positions: positions, cursor: (0, 0) };
+    }
+}
+
+#[pymethods]
+impl EdgeIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        return slf;
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(String, String, f32, bool)> {
+        let graph_rc = Rc::clone(&slf.graph);
+        let graph = graph_rc.borrow();
+
+        // Nodes added since the last step are looked up again
+        if slf.positions.len() != graph.nodes.len() {
+            slf.positions = graph.edges().positions();
+        }
+
+        let mut edges = Edges::resume(&graph, Rc::clone(&slf.positions), slf.cursor);
+        let edge = edges.next();
+        slf.cursor = edges.cursor();
+
+        let edge = edge?;
+        let from = graph.nodes[edge.from].borrow().label.clone();
+        let to = graph.nodes[edge.to].borrow().label.clone();
+
+        return Some((from, to, edge.weight, edge.directed));
+    }
+}
@@ -3,19 +3,192 @@ use pyo3::prelude::*;
 use std::cell::RefCell;
 use std::rc::{Rc};
 use crate::graph_core::graph::{_Graph,ConnectionProperty};
+use crate::graph_core::node::{Connection, _Node};
 use crate::graph_py::py_node::Node;
+use crate::graph_py::py_paths::SimplePathIterator;
+use crate::graph_py::py_edges::EdgeIterator;
+use crate::graph_py::py_temporal::WindowIterator;
 use crate::layout::layout::Layout;
 use crate::layout::style::GraphStyle;
 use crate::external_apis::core::OpenAlexGraphType;
+use crate::algorithms::main_path::TraversalWeight;
+use crate::algorithms::bipartite::ProjectionMethod;
+use crate::algorithms::motifs::MotifCensus;
+use crate::algorithms::null_models::NullModel;
+use crate::algorithms::link_prediction::LinkPredictor;
+use crate::algorithms::similarity::SimilarityMethod;
+use crate::algorithms::paths::{WeightFn, WeightInterpretation};
+use crate::algorithms::robustness::{RemovalStrategy, RemovalTarget, RobustnessResult};
+use crate::algorithms::quotient::EdgeAggregation;
+use crate::algorithms::power_law::{DegreeType, PowerLawFit};
+use crate::algorithms::backbone::BackboneMethod;
+use crate::algorithms::traversal::{Traversal, TraversalVisitor, VisitAction};
+use crate::algorithms::isomorphism::Isomorphism;
+use crate::algorithms::subgraph::copy_graph;
+use crate::algorithms::temporal::window_schedule;
+use crate::simulations::diffusion::SimulationResult;
+use crate::simulations::influence::{CascadeModel, SeedSelection};
 use pyo3::types::PyDict;
+use pyo3::call::PyCallArgs;
+use pyo3::exceptions::PyValueError;
+use std::collections::{HashMap, HashSet};
 use pyo3_stub_gen::derive::gen_stub_pyclass;
 
 
 
+fn nodes_by_label(graph: &_Graph) -> HashMap<String, Rc<RefCell<_Node>>> {
+    return graph.nodes
+        .iter()
+        .map(|n| (n.borrow().label.clone(), Rc::clone(n)))
+        .collect();
+}
+
+fn connection_dict<'py>(py: Python<'py>, conn: &Connection) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("weight", conn.weight)?;
+    dict.set_item("directed", conn.directed)?;
+
+    return Ok(dict);
+}
+
+/*
+ * Python callbacks are called from Rust closures that must return a plain
+ * value, so the first exception raised is kept here and re-raised afterwards.
+ */
+fn callback_result<T: Default>(result: PyResult<T>, error: &RefCell<Option<PyErr>>) -> T {
+    match result {
+        Ok(value) => return value,
+        Err(e) => {
+            if error.borrow().is_none() {
+                *error.borrow_mut() = Some(e);
+            }
+            return T::default();
+        }
+    }
+}
+
+/*
+ * Traversal visitor calling the Python callbacks given to dfs and bfs. A
+ * callback returns None or a VisitAction; the first exception raised stops
+ * the traversal and is kept to be raised afterwards.
+ */
+struct PyVisitor<'py> {
+    py: Python<'py>,
+    on_discover: Option<Py<PyAny>>,
+    on_finish: Option<Py<PyAny>>,
+    on_tree_edge: Option<Py<PyAny>>,
+    on_back_edge: Option<Py<PyAny>>,
+    on_cross_edge: Option<Py<PyAny>>,
+    order: Vec<String>,
+    error: Option<PyErr>
+}
+
+fn visit_callback<'py>(py: Python<'py>, callback: &Option<Py<PyAny>>, args: impl PyCallArgs<'py>, error: &mut Option<PyErr>) -> VisitAction {
+    let Some(callback) = callback else {
+        return VisitAction::Continue;
+    };
+
+    let result = callback
+        .call1(py, args)
+        .and_then(|r| r.extract::<Option<VisitAction>>(py).map_err(PyErr::from));
+
+    match result {
+        Ok(action) => return action.unwrap_or(VisitAction::Continue),
+        Err(e) => {
+            *error = Some(e);
+            return VisitAction::Stop;
+        }
+    }
+}
+
+impl<'py> TraversalVisitor for PyVisitor<'py> {
+    fn discover(&mut self, node: &str, depth: usize) -> VisitAction {
+        self.order.push(node.to_string());
+        return visit_callback(self.py, &self.on_discover, (node, depth), &mut self.error);
+    }
+
+    fn finish(&mut self, node: &str, depth: usize) -> VisitAction {
+        return visit_callback(self.py, &self.on_finish, (node, depth), &mut self.error);
+    }
+
+    fn tree_edge(&mut self, from: &str, to: &str, weight: f32) -> VisitAction {
+        return visit_callback(self.py, &self.on_tree_edge, (from, to, weight), &mut self.error);
+    }
+
+    fn back_edge(&mut self, from: &str, to: &str, weight: f32) -> VisitAction {
+        return visit_callback(self.py, &self.on_back_edge, (from, to, weight), &mut self.error);
+    }
+
+    fn cross_edge(&mut self, from: &str, to: &str, weight: f32) -> VisitAction {
+        return visit_callback(self.py, &self.on_cross_edge, (from, to, weight), &mut self.error);
+    }
+}
+
+/*
+ * Rates and probabilities of the spreading models must lie in [0, 1].
+ */
+fn check_probability(name: &str, value: f64) -> PyResult<()> {
+    if !(0. ..=1.).contains(&value) {
+        return Err(PyValueError::new_err(format!("'{}' must be between 0 and 1, got {}", name, value)));
+    }
+
+    return Ok(());
+}
+
+/*
+ * Restart and burn probabilities of the samplers must lie in [0, 1): with 1
+ * a walk would never leave its start and a fire would never stop.
+ */
+fn check_sampling_probability(name: &str, value: f64) -> PyResult<()> {
+    if !(0. ..1.).contains(&value) {
+        return Err(PyValueError::new_err(format!("'{}' must be at least 0 and less than 1, got {}", name, value)));
+    }
+
+    return Ok(());
+}
+
+/*
+ * Path methods take either a WeightInterpretation or a callable turning a
+ * connection weight into a length. With a callable the path algorithm runs
+ * on a copy of the graph, so the callable can change the graph itself.
+ */
+#[derive(FromPyObject)]
+pub enum PathWeights {
+    Interpretation(WeightInterpretation),
+    Callback(Py<PyAny>),
+}
+
+fn with_path_weights<T>(
+    py: Python<'_>,
+    graph: &RefCell<_Graph>,
+    weights: &PathWeights,
+    run: impl FnOnce(&_Graph, WeightInterpretation, Option<WeightFn>) -> T
+) -> PyResult<T> {
+    match weights {
+        PathWeights::Interpretation(interpretation) => return Ok(run(&graph.borrow(), interpretation.clone(), None)),
+        PathWeights::Callback(callback) => {
+            let snapshot = copy_graph(&graph.borrow());
+            let error: RefCell<Option<PyErr>> = RefCell::new(None);
+            let weight_fn = |weight: f32| -> f64 {
+                let result = callback.call1(py, (weight,)).and_then(|r| r.extract::<f64>(py));
+                return callback_result(result, &error);
+            };
+
+            let result = run(&snapshot, WeightInterpretation::Distance, Some(&weight_fn));
+
+            if let Some(e) = error.into_inner() {
+                return Err(e);
+            }
+
+            return Ok(result);
+        }
+    }
+}
+
 #[gen_stub_pyclass]
 #[pyclass(unsendable, module="netfog")]
 pub struct Graph {
-    inner: Rc<RefCell<_Graph>>,
+    pub(crate) inner: Rc<RefCell<_Graph>>,
 }
 
 #[pymethods]
@@ -34,9 +207,28 @@ impl Graph {
         return Py::new(py, node);
     }
 
-    #[pyo3(signature = (from_label, to_label, weight=0., directed=false))]
-    fn create_connection(&self, from_label: String, to_label: String, weight: f32, directed: Option<bool>) {
-        self.inner.borrow_mut().create_connection(from_label, to_label, weight, directed);
+    /*
+     * With 'start' the connection is temporal: it exists only at that time,
+     * or during [start, end] when 'end' is given too.
+     */
+    #[pyo3(signature = (from_label, to_label, weight=0., directed=false, start=None, end=None))]
+    fn create_connection(&self, from_label: String, to_label: String, weight: f32, directed: Option<bool>, start: Option<f64>, end: Option<f64>) -> PyResult<()> {
+        match start {
+            Some(start) => {
+                if end.map_or(false, |end| end < start) {
+                    return Err(PyValueError::new_err("Connection ends before it starts"));
+                }
+                self.inner.borrow_mut().create_temporal_connection(from_label, to_label, weight, directed, start, end);
+            },
+            None => {
+                if end.is_some() {
+                    return Err(PyValueError::new_err("'end' requires 'start'"));
+                }
+                self.inner.borrow_mut().create_connection(from_label, to_label, weight, directed);
+            }
+        }
+
+        return Ok(());
     }
 
     fn node_by_label(&self, node_label: &str, py: Python<'_>) ->  PyResult<Py<Node>> {
@@ -69,6 +261,13 @@ impl Graph {
         Ok(py_connections)
     }
 
+    /*
+     * Lazy iterator over the connections as (from, to, weight, directed).
+     */
+    pub fn edges(&self) -> EdgeIterator {
+        return EdgeIterator::new(&self.inner);
+    }
+
     fn generate_adjacency_matrix(&self) -> PyResult<Vec<Vec<f32>>> {
         return Ok(self.inner.borrow_mut().generate_adjacency_matrix());
     }
@@ -157,6 +356,102 @@ impl Graph {
         return Ok(distribution.into());
     }
 
+    pub fn describe(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let summary = self.inner.borrow().describe();
+        let description = PyDict::new(py);
+
+        description.set_item("node_count", summary.node_count)?;
+        description.set_item("edge_count", summary.edge_count)?;
+        description.set_item("directed_edge_count", summary.directed_edge_count)?;
+        description.set_item("undirected_edge_count", summary.undirected_edge_count)?;
+        description.set_item("density", summary.density)?;
+        description.set_item("weight_min", summary.weight_min)?;
+        description.set_item("weight_max", summary.weight_max)?;
+        description.set_item("weight_mean", summary.weight_mean)?;
+        description.set_item("weight_median", summary.weight_median)?;
+        description.set_item("degree_min", summary.degree_min)?;
+        description.set_item("degree_max", summary.degree_max)?;
+        description.set_item("degree_mean", summary.degree_mean)?;
+        description.set_item("degree_median", summary.degree_median)?;
+        description.set_item("weakly_connected_components", summary.weakly_connected_components)?;
+        description.set_item("strongly_connected_components", summary.strongly_connected_components)?;
+        description.set_item("isolated_nodes", summary.isolated_nodes)?;
+        description.set_item("self_loop_count", summary.self_loop_count)?;
+        description.set_item("multi_edge_count", summary.multi_edge_count)?;
+        description.set_item("build_time_ms", summary.build_time_ms)?;
+
+        return Ok(description.into());
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        let inner = self.inner.borrow();
+        let (mut directed, mut undirected) = (0, 0);
+        for edge in inner.edges() {
+            if edge.directed { directed += 1 } else { undirected += 1 }
+        }
+
+        return Ok(format!(
+            "Graph(nodes={}, edges={}, directed={}, undirected={})",
+            inner.nodes.len(), directed + undirected, directed, undirected
+        ));
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        return Ok(self.inner.borrow().describe().to_string());
+    }
+
+    #[pyo3(signature = (degree=DegreeType::All, xmin=None, n_bootstrap=100, seed=None))]
+    pub fn power_law_fit(&self, degree: DegreeType, xmin: Option<usize>, n_bootstrap: usize, seed: Option<u64>) -> PyResult<PowerLawFit> {
+        let fit = self.inner.borrow().power_law_fit(degree, xmin, n_bootstrap, seed)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(fit);
+    }
+
+    pub fn node_metrics(&self, metrics: Vec<String>, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let table = self.inner.borrow().node_metrics(&metrics)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let columns = PyDict::new(py);
+
+        columns.set_item("label", table.label)?;
+        for (name, column) in [
+            ("in_degree", table.in_degree),
+            ("out_degree", table.out_degree),
+            ("total_degree", table.total_degree),
+            ("undirected_degree", table.undirected_degree),
+        ] {
+            if let Some(values) = column {
+                columns.set_item(name, values)?;
+            }
+        }
+
+        for (name, column) in [
+            ("in_strength", table.in_strength),
+            ("out_strength", table.out_strength),
+            ("total_strength", table.total_strength),
+            ("in_centrality", table.in_centrality),
+            ("out_centrality", table.out_centrality),
+            ("total_centrality", table.total_centrality),
+            ("undirected_centrality", table.undirected_centrality),
+        ] {
+            if let Some(values) = column {
+                columns.set_item(name, values)?;
+            }
+        }
+
+        for (name, column) in [
+            ("clustering", table.clustering),
+            ("betweenness", table.betweenness),
+            ("closeness", table.closeness),
+        ] {
+            if let Some(values) = column {
+                columns.set_item(name, values)?;
+            }
+        }
+
+        return Ok(columns.into());
+    }
+
     pub fn compute_entropy(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
         let mut inner = self.inner.borrow_mut();
         let entropy_snapshot = inner.compute_entropy();
@@ -186,19 +481,101 @@ impl Graph {
         return Ok(skewness.into());
     }
 
-    pub fn dfs(&mut self, start_node_label: &str) -> Vec<String> {
-        let elements = self.inner.borrow_mut().dfs(start_node_label);
-        return elements;
+    pub fn triad_census(&self) -> HashMap<String, f64> {
+        return self.inner.borrow().triad_census();
     }
 
-    pub fn bfs(&mut self, start_node_label: &str) -> Vec<String> {
-        let elements = self.inner.borrow_mut().bfs(start_node_label);
-        return elements;
+    #[pyo3(signature = (motif_size=3))]
+    pub fn motif_census(&self, motif_size: usize) -> PyResult<HashMap<String, f64>> {
+        if motif_size != 3 && motif_size != 4 {
+            return Err(PyValueError::new_err("motif_size must be 3 or 4"));
+        }
+
+        return Ok(self.inner.borrow().motif_census(motif_size));
     }
 
-    pub fn dijkstra(&self, start_node_label: &str, py: Python<'_>) -> PyResult<Py<PyDict>> {
-        let mut inner = self.inner.borrow_mut();
-        let dijkstra_snapshot = inner.dijkstra(start_node_label);
+    #[pyo3(signature = (census=MotifCensus::Triads, n_samples=100, null_model=NullModel::Gnm, seed=None))]
+    pub fn motif_z_scores(&self, census: MotifCensus, n_samples: usize, null_model: NullModel, seed: Option<u64>) -> HashMap<String, f64> {
+        return self.inner.borrow().motif_z_scores(census, n_samples, null_model, seed);
+    }
+
+    /*
+     * Labels in discovery order. The optional callbacks are called on each
+     * traversal event, on_discover/on_finish with (label, depth) and the edge
+     * ones with (from, to, weight), and may return a VisitAction to prune or
+     * stop the search.
+     */
+    #[pyo3(signature = (start_node_label, on_discover=None, on_finish=None, on_tree_edge=None, on_back_edge=None, on_cross_edge=None, max_depth=None))]
+    pub fn dfs(
+        &self,
+        start_node_label: &str,
+        on_discover: Option<Py<PyAny>>,
+        on_finish: Option<Py<PyAny>>,
+        on_tree_edge: Option<Py<PyAny>>,
+        on_back_edge: Option<Py<PyAny>>,
+        on_cross_edge: Option<Py<PyAny>>,
+        max_depth: Option<usize>,
+        py: Python<'_>
+    ) -> PyResult<Vec<String>> {
+        let mut visitor = PyVisitor {
+            py: py,
+            on_discover: on_discover,
+            on_finish: on_finish,
+            on_tree_edge: on_tree_edge,
+            on_back_edge: on_back_edge,
+            on_cross_edge: on_cross_edge,
+            order: Vec::new(),
+            error: None
+        };
+        // The graph is only borrowed while the traversal is set up, so the callbacks can change it
+        let traversal = Traversal::new(&self.inner.borrow(), start_node_label);
+        traversal.dfs(&mut visitor, max_depth);
+
+        if let Some(e) = visitor.error {
+            return Err(e);
+        }
+
+        return Ok(visitor.order);
+    }
+
+    #[pyo3(signature = (start_node_label, on_discover=None, on_finish=None, on_tree_edge=None, on_back_edge=None, on_cross_edge=None, max_depth=None))]
+    pub fn bfs(
+        &self,
+        start_node_label: &str,
+        on_discover: Option<Py<PyAny>>,
+        on_finish: Option<Py<PyAny>>,
+        on_tree_edge: Option<Py<PyAny>>,
+        on_back_edge: Option<Py<PyAny>>,
+        on_cross_edge: Option<Py<PyAny>>,
+        max_depth: Option<usize>,
+        py: Python<'_>
+    ) -> PyResult<Vec<String>> {
+        let mut visitor = PyVisitor {
+            py: py,
+            on_discover: on_discover,
+            on_finish: on_finish,
+            on_tree_edge: on_tree_edge,
+            on_back_edge: on_back_edge,
+            on_cross_edge: on_cross_edge,
+            order: Vec::new(),
+            error: None
+        };
+        let traversal = Traversal::new(&self.inner.borrow(), start_node_label);
+        traversal.bfs(&mut visitor, max_depth);
+
+        if let Some(e) = visitor.error {
+            return Err(e);
+        }
+
+        return Ok(visitor.order);
+    }
+
+    #[pyo3(signature = (start_node_label, weights=PathWeights::Interpretation(WeightInterpretation::Distance)))]
+    pub fn dijkstra(&self, start_node_label: &str, weights: PathWeights, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let distances = with_path_weights(py, &self.inner, &weights, |graph, interpretation, weight_fn| {
+            return graph.dijkstra(start_node_label, interpretation, weight_fn);
+        })?;
+        let dijkstra_snapshot = distances.map_err(|e| PyValueError::new_err(e.to_string()))?;
         let dijkstra = PyDict::new(py);
 
         for (key, value) in dijkstra_snapshot.iter() {
@@ -208,6 +585,518 @@ impl Graph {
         return Ok(dijkstra.into());
     }
 
+    #[pyo3(signature = (source_label, target_label, k, weights=PathWeights::Interpretation(WeightInterpretation::Distance)))]
+    pub fn k_shortest_paths(
+        &self,
+        source_label: &str,
+        target_label: &str,
+        k: usize,
+        weights: PathWeights,
+        py: Python<'_>
+    ) -> PyResult<Vec<(Vec<String>, f64)>> {
+        let paths = with_path_weights(py, &self.inner, &weights, |graph, interpretation, weight_fn| {
+            return graph.k_shortest_paths(source_label, target_label, k, interpretation, weight_fn);
+        })?;
+
+        return paths.map_err(|e| PyValueError::new_err(e.to_string()));
+    }
+
+    #[pyo3(signature = (source_label, target_label, cutoff=None))]
+    pub fn all_simple_paths(&self, source_label: &str, target_label: &str, cutoff: Option<usize>) -> SimplePathIterator {
+        return SimplePathIterator {
+            inner: self.inner.borrow().all_simple_paths(source_label, target_label, cutoff),
+        };
+    }
+
+    pub fn is_dag(&self) -> bool {
+        return self.inner.borrow().is_dag();
+    }
+
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        return self.inner.borrow().find_cycle();
+    }
+
+    pub fn simple_cycles(&self) -> Vec<Vec<String>> {
+        return self.inner.borrow().simple_cycles();
+    }
+
+    pub fn topological_sort(&self) -> PyResult<Vec<String>> {
+        let order = self.inner.borrow().topological_sort()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(order);
+    }
+
+    #[pyo3(signature = (weights=PathWeights::Interpretation(WeightInterpretation::Unweighted)))]
+    pub fn dag_longest_path(&self, weights: PathWeights, py: Python<'_>) -> PyResult<Vec<String>> {
+        let path = with_path_weights(py, &self.inner, &weights, |graph, interpretation, weight_fn| {
+            return graph.dag_longest_path(interpretation, weight_fn);
+        })?;
+
+        return path.map_err(|e| PyValueError::new_err(e.to_string()));
+    }
+
+    pub fn ancestors(&self, node_label: &str) -> HashSet<String> {
+        return self.inner.borrow().ancestors(node_label);
+    }
+
+    pub fn descendants(&self, node_label: &str) -> HashSet<String> {
+        return self.inner.borrow().descendants(node_label);
+    }
+
+    #[pyo3(signature = (weight_type=TraversalWeight::Spc))]
+    pub fn traversal_weights(&self, weight_type: TraversalWeight) -> PyResult<Vec<(String, String, f64)>> {
+        let weights = self.inner.borrow().traversal_weights(weight_type)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(weights);
+    }
+
+    #[pyo3(signature = (weight_type=TraversalWeight::Spc))]
+    pub fn local_main_path(&self, weight_type: TraversalWeight) -> PyResult<Graph> {
+        let graph = self.inner.borrow().local_main_path(weight_type)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(Graph { inner: Rc::new(RefCell::new(graph)) });
+    }
+
+    #[pyo3(signature = (weight_type=TraversalWeight::Spc))]
+    pub fn global_main_path(&self, weight_type: TraversalWeight) -> PyResult<Graph> {
+        let graph = self.inner.borrow().global_main_path(weight_type)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(Graph { inner: Rc::new(RefCell::new(graph)) });
+    }
+
+    #[pyo3(signature = (key_routes=1, weight_type=TraversalWeight::Spc))]
+    pub fn key_route_main_path(&self, key_routes: usize, weight_type: TraversalWeight) -> PyResult<Graph> {
+        let graph = self.inner.borrow().key_route_main_path(key_routes, weight_type)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(Graph { inner: Rc::new(RefCell::new(graph)) });
+    }
+
+    pub fn is_bipartite(&self) -> bool {
+        return self.inner.borrow().is_bipartite();
+    }
+
+    pub fn bipartite_sets(&self) -> PyResult<(Vec<String>, Vec<String>)> {
+        let sets = self.inner.borrow().bipartite_sets()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(sets);
+    }
+
+    #[pyo3(signature = (node_labels, method=ProjectionMethod::Simple))]
+    pub fn bipartite_projection(&self, node_labels: Vec<String>, method: ProjectionMethod) -> Graph {
+        let graph = self.inner.borrow().bipartite_projection(&node_labels, method);
+
+        return Graph { inner: Rc::new(RefCell::new(graph)) };
+    }
+
+    pub fn maximum_matching(&self) -> PyResult<Vec<(String, String)>> {
+        let matching = self.inner.borrow().maximum_matching()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(matching);
+    }
+
+    #[pyo3(signature = (other, node_match=None, edge_match=None))]
+    pub fn is_isomorphic(&self, other: &Graph, node_match: Option<Py<PyAny>>, edge_match: Option<Py<PyAny>>, py: Python<'_>) -> PyResult<bool> {
+        let self_nodes = nodes_by_label(&self.inner.borrow());
+        let other_nodes = nodes_by_label(&other.inner.borrow());
+        // Both graphs are only borrowed while they are copied, so the callbacks can change them
+        let isomorphism = Isomorphism::new(&self.inner.borrow(), &other.inner.borrow());
+        let error: RefCell<Option<PyErr>> = RefCell::new(None);
+
+        let node_fn = |a: &_Node, b: &_Node| -> bool {
+            let node_a = Node { inner: Rc::clone(&self_nodes[&a.label]) };
+            let node_b = Node { inner: Rc::clone(&other_nodes[&b.label]) };
+            let result = node_match.as_ref().unwrap().call1(py, (node_a, node_b)).and_then(|r| r.extract::<bool>(py));
+            return callback_result(result, &error);
+        };
+
+        let edge_fn = |a: &Connection, b: &Connection| -> bool {
+            let result = connection_dict(py, a)
+                .and_then(|dict_a| Ok((dict_a, connection_dict(py, b)?)))
+                .and_then(|args| edge_match.as_ref().unwrap().call1(py, args))
+                .and_then(|r| r.extract::<bool>(py));
+            return callback_result(result, &error);
+        };
+
+        let isomorphic = isomorphism.is_isomorphic(
+            node_match.as_ref().map(|_| &node_fn as &dyn Fn(&_Node, &_Node) -> bool),
+            edge_match.as_ref().map(|_| &edge_fn as &dyn Fn(&Connection, &Connection) -> bool)
+        );
+
+        if let Some(e) = error.into_inner() {
+            return Err(e);
+        }
+
+        return Ok(isomorphic);
+    }
+
+    #[pyo3(signature = (pattern, induced=true, node_match=None, edge_match=None))]
+    pub fn subgraph_isomorphisms(
+        &self,
+        pattern: &Graph,
+        induced: bool,
+        node_match: Option<Py<PyAny>>,
+        edge_match: Option<Py<PyAny>>,
+        py: Python<'_>
+    ) -> PyResult<Vec<HashMap<String, String>>> {
+        let self_nodes = nodes_by_label(&self.inner.borrow());
+        let pattern_nodes = nodes_by_label(&pattern.inner.borrow());
+        let isomorphism = Isomorphism::new(&self.inner.borrow(), &pattern.inner.borrow());
+        let error: RefCell<Option<PyErr>> = RefCell::new(None);
+
+        let node_fn = |a: &_Node, b: &_Node| -> bool {
+            let node_a = Node { inner: Rc::clone(&self_nodes[&a.label]) };
+            let node_b = Node { inner: Rc::clone(&pattern_nodes[&b.label]) };
+            let result = node_match.as_ref().unwrap().call1(py, (node_a, node_b)).and_then(|r| r.extract::<bool>(py));
+            return callback_result(result, &error);
+        };
+
+        let edge_fn = |a: &Connection, b: &Connection| -> bool {
+            let result = connection_dict(py, a)
+                .and_then(|dict_a| Ok((dict_a, connection_dict(py, b)?)))
+                .and_then(|args| edge_match.as_ref().unwrap().call1(py, args))
+                .and_then(|r| r.extract::<bool>(py));
+            return callback_result(result, &error);
+        };
+
+        let mappings = isomorphism.subgraph_isomorphisms(
+            induced,
+            node_match.as_ref().map(|_| &node_fn as &dyn Fn(&_Node, &_Node) -> bool),
+            edge_match.as_ref().map(|_| &edge_fn as &dyn Fn(&Connection, &Connection) -> bool)
+        );
+
+        if let Some(e) = error.into_inner() {
+            return Err(e);
+        }
+
+        return Ok(mappings);
+    }
+
+    #[pyo3(signature = (walk_length=10, walks_per_node=1, weighted=false, seed=None))]
+    pub fn random_walks(&self, walk_length: usize, walks_per_node: usize, weighted: bool, seed: Option<u64>) -> Vec<Vec<String>> {
+        return self.inner.borrow().random_walks(walk_length, walks_per_node, weighted, seed);
+    }
+
+    #[pyo3(signature = (walk_length=10, walks_per_node=1, p=1., q=1., weighted=false, seed=None))]
+    pub fn node2vec_walks(
+        &self,
+        walk_length: usize,
+        walks_per_node: usize,
+        p: f64,
+        q: f64,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> PyResult<Vec<Vec<String>>> {
+        if p <= 0. || q <= 0. {
+            return Err(PyValueError::new_err("p and q must be positive"));
+        }
+
+        return Ok(self.inner.borrow().node2vec_walks(walk_length, walks_per_node, p, q, weighted, seed));
+    }
+
+    #[pyo3(signature = (method=LinkPredictor::AdamicAdar, candidates=None, top_k=None, beta=0.05))]
+    pub fn link_prediction(
+        &self,
+        method: LinkPredictor,
+        candidates: Option<Vec<(String, String)>>,
+        top_k: Option<usize>,
+        beta: f64
+    ) -> Vec<(String, String, f64)> {
+        return self.inner.borrow().link_prediction(method, candidates.as_ref(), top_k, beta);
+    }
+
+    #[pyo3(signature = (method=SimilarityMethod::SimRank, iterations=10, decay=0.8))]
+    pub fn similarity_matrix(&self, method: SimilarityMethod, iterations: usize, decay: f64) -> Vec<Vec<f64>> {
+        return self.inner.borrow().similarity_matrix(method, iterations, decay);
+    }
+
+    #[pyo3(signature = (k=5, method=SimilarityMethod::SimRank, iterations=10, decay=0.8))]
+    pub fn most_similar(&self, k: usize, method: SimilarityMethod, iterations: usize, decay: f64) -> HashMap<String, Vec<(String, f64)>> {
+        return self.inner.borrow().most_similar(k, method, iterations, decay);
+    }
+
+    pub fn articulation_points(&self) -> HashSet<String> {
+        return self.inner.borrow().articulation_points();
+    }
+
+    pub fn bridges(&self) -> Vec<(String, String)> {
+        return self.inner.borrow().bridges();
+    }
+
+    pub fn biconnected_components(&self) -> Vec<HashSet<String>> {
+        return self.inner.borrow().biconnected_components();
+    }
+
+    pub fn biconnected_component_edges(&self) -> Vec<Vec<(String, String)>> {
+        return self.inner.borrow().biconnected_component_edges();
+    }
+
+    pub fn maximal_cliques(&self) -> Vec<Vec<String>> {
+        return self.inner.borrow().maximal_cliques();
+    }
+
+    pub fn clique_number(&self) -> usize {
+        return self.inner.borrow().clique_number();
+    }
+
+    pub fn node_clique_number(&self) -> HashMap<String, usize> {
+        return self.inner.borrow().node_clique_number();
+    }
+
+    pub fn k_clique_communities(&self, k: usize) -> PyResult<Vec<HashSet<String>>> {
+        if k < 2 {
+            return Err(PyValueError::new_err("k must be at least 2"));
+        }
+
+        return Ok(self.inner.borrow().k_clique_communities(k));
+    }
+
+    #[pyo3(signature = (target=RemovalTarget::Nodes, strategy=RemovalStrategy::Degree, adaptive=false, max_removals=None, seed=None, weights=PathWeights::Interpretation(WeightInterpretation::Unweighted)))]
+    pub fn robustness(
+        &self,
+        target: RemovalTarget,
+        strategy: RemovalStrategy,
+        adaptive: bool,
+        max_removals: Option<usize>,
+        seed: Option<u64>,
+        weights: PathWeights,
+        py: Python<'_>
+    ) -> PyResult<RobustnessResult> {
+        let result = with_path_weights(py, &self.inner, &weights, |graph, interpretation, weight_fn| {
+            return graph.robustness(target, strategy, adaptive, max_removals, seed, interpretation, weight_fn);
+        })?;
+
+        return result.map_err(|e| PyValueError::new_err(e.to_string()));
+    }
+
+    #[pyo3(signature = (n_swaps=None, seed=None))]
+    pub fn double_edge_swap(&self, n_swaps: Option<usize>, seed: Option<u64>) -> Graph {
+        let graph = self.inner.borrow().double_edge_swap(n_swaps, seed);
+        return Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        }
+    }
+
+    #[pyo3(signature = (n_swaps=None, seed=None))]
+    pub fn directed_edge_swap(&self, n_swaps: Option<usize>, seed: Option<u64>) -> Graph {
+        let graph = self.inner.borrow().directed_edge_swap(n_swaps, seed);
+        return Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        }
+    }
+
+    #[pyo3(signature = (metric_fn, n_samples=100, seed=None))]
+    pub fn significance(&self, metric_fn: Py<PyAny>, n_samples: usize, seed: Option<u64>, py: Python<'_>) -> PyResult<HashMap<String, f64>> {
+        let error: RefCell<Option<PyErr>> = RefCell::new(None);
+        let mut metric = |graph: _Graph| -> f64 {
+            let sample = Graph { inner: Rc::new(RefCell::new(graph)) };
+            let result = metric_fn.call1(py, (sample,)).and_then(|r| r.extract::<f64>(py));
+            return callback_result(result, &error);
+        };
+
+        // The samples are drawn from a copy, so the metric can change the graph
+        let snapshot = copy_graph(&self.inner.borrow());
+        let result = snapshot.significance(&mut metric, n_samples, seed);
+
+        if let Some(e) = error.into_inner() {
+            return Err(e);
+        }
+
+        return Ok(HashMap::from([
+            ("observed".to_string(), result.observed),
+            ("mean".to_string(), result.mean),
+            ("std".to_string(), result.std),
+            ("z_score".to_string(), result.z_score),
+            ("p_value".to_string(), result.p_value),
+        ]));
+    }
+
+    #[pyo3(signature = (target_size, seed=None))]
+    pub fn random_node_sample(&self, target_size: usize, seed: Option<u64>) -> Graph {
+        let graph = self.inner.borrow().random_node_sample(target_size, seed);
+        return Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        }
+    }
+
+    #[pyo3(signature = (target_size, seed=None))]
+    pub fn random_edge_sample(&self, target_size: usize, seed: Option<u64>) -> Graph {
+        let graph = self.inner.borrow().random_edge_sample(target_size, seed);
+        return Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        }
+    }
+
+    #[pyo3(signature = (target_size, depth=None, start_node=None, seed=None))]
+    pub fn snowball_sample(&self, target_size: usize, depth: Option<usize>, start_node: Option<String>, seed: Option<u64>) -> Graph {
+        let graph = self.inner.borrow().snowball_sample(target_size, depth, start_node.as_deref(), seed);
+        return Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        }
+    }
+
+    #[pyo3(signature = (target_size, restart_probability=0.15, seed=None))]
+    pub fn random_walk_sample(&self, target_size: usize, restart_probability: f64, seed: Option<u64>) -> PyResult<Graph> {
+        check_sampling_probability("restart_probability", restart_probability)?;
+
+        let graph = self.inner.borrow().random_walk_sample(target_size, restart_probability, seed);
+        return Ok(Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        });
+    }
+
+    #[pyo3(signature = (target_size, burn_probability=0.7, seed=None))]
+    pub fn forest_fire_sample(&self, target_size: usize, burn_probability: f64, seed: Option<u64>) -> PyResult<Graph> {
+        check_sampling_probability("burn_probability", burn_probability)?;
+
+        let graph = self.inner.borrow().forest_fire_sample(target_size, burn_probability, seed);
+        return Ok(Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        });
+    }
+
+    #[pyo3(signature = (groups, aggregation=EdgeAggregation::Sum, self_loops=false))]
+    pub fn quotient_graph(&self, groups: HashMap<String, String>, aggregation: EdgeAggregation, self_loops: bool) -> PyResult<Graph> {
+        let graph = self.inner.borrow().quotient_graph(&groups, aggregation, self_loops)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        return Ok(Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        });
+    }
+
+    #[pyo3(signature = (method=BackboneMethod::DisparityFilter, threshold=None))]
+    pub fn backbone(&self, method: BackboneMethod, threshold: Option<f64>) -> PyResult<(Graph, Vec<(String, String, f64)>)> {
+        let (graph, significance) = self.inner.borrow().backbone(method, threshold)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let backbone = Graph {
+            inner: Rc::new(RefCell::new(graph)),
+        };
+
+        return Ok((backbone, significance));
+    }
+
+    pub fn time_span(&self) -> Option<(f64, f64)> {
+        return self.inner.borrow().time_span();
+    }
+
+    pub fn snapshot(&self, time: f64) -> Graph {
+        let graph = self.inner.borrow().snapshot(time);
+
+        return Graph { inner: Rc::new(RefCell::new(graph)) };
+    }
+
+    pub fn window(&self, start: f64, end: f64) -> Graph {
+        let graph = self.inner.borrow().window(start, end);
+
+        return Graph { inner: Rc::new(RefCell::new(graph)) };
+    }
+
+    #[pyo3(signature = (width, step=None, start=None, end=None))]
+    pub fn sliding_windows(&self, width: f64, step: Option<f64>, start: Option<f64>, end: Option<f64>) -> PyResult<WindowIterator> {
+        if !(width > 0.) || !(step.unwrap_or(width) > 0.) {
+            return Err(PyValueError::new_err("Window width and step must be positive"));
+        }
+
+        let schedule = window_schedule(&self.inner.borrow(), width, step, start, end);
+
+        return Ok(WindowIterator { graph: Rc::clone(&self.inner), schedule: schedule });
+    }
+
+    #[pyo3(signature = (source_label, start_time=None))]
+    pub fn temporal_reachability(&self, source_label: &str, start_time: Option<f64>) -> HashMap<String, f64> {
+        return self.inner.borrow().temporal_reachability(source_label, start_time);
+    }
+
+    #[pyo3(signature = (source_label, target_label, start_time=None))]
+    pub fn time_respecting_path(&self, source_label: &str, target_label: &str, start_time: Option<f64>) -> Option<Vec<(String, String, f64)>> {
+        return self.inner.borrow().time_respecting_path(source_label, target_label, start_time);
+    }
+
+    #[pyo3(signature = (seed_nodes, beta=0.1, gamma=0.05, max_steps=100, weighted=false, seed=None))]
+    pub fn sir(
+        &self,
+        seed_nodes: Vec<String>,
+        beta: f64,
+        gamma: f64,
+        max_steps: usize,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> PyResult<SimulationResult> {
+        check_probability("beta", beta)?;
+        check_probability("gamma", gamma)?;
+
+        return Ok(self.inner.borrow().sir(&seed_nodes, beta, gamma, max_steps, weighted, seed));
+    }
+
+    #[pyo3(signature = (seed_nodes, beta=0.1, gamma=0.05, max_steps=100, weighted=false, seed=None))]
+    pub fn sis(
+        &self,
+        seed_nodes: Vec<String>,
+        beta: f64,
+        gamma: f64,
+        max_steps: usize,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> PyResult<SimulationResult> {
+        check_probability("beta", beta)?;
+        check_probability("gamma", gamma)?;
+
+        return Ok(self.inner.borrow().sis(&seed_nodes, beta, gamma, max_steps, weighted, seed));
+    }
+
+    #[pyo3(signature = (seed_nodes, probability=0.1, weighted=false, max_steps=None, seed=None))]
+    pub fn independent_cascade(
+        &self,
+        seed_nodes: Vec<String>,
+        probability: f64,
+        weighted: bool,
+        max_steps: Option<usize>,
+        seed: Option<u64>
+    ) -> PyResult<SimulationResult> {
+        check_probability("probability", probability)?;
+
+        return Ok(self.inner.borrow().independent_cascade(&seed_nodes, probability, weighted, max_steps, seed));
+    }
+
+    #[pyo3(signature = (seed_nodes, threshold=None, weighted=false, max_steps=None, seed=None))]
+    pub fn linear_threshold(
+        &self,
+        seed_nodes: Vec<String>,
+        threshold: Option<f64>,
+        weighted: bool,
+        max_steps: Option<usize>,
+        seed: Option<u64>
+    ) -> PyResult<SimulationResult> {
+        if let Some(threshold) = threshold {
+            check_probability("threshold", threshold)?;
+        }
+
+        return Ok(self.inner.borrow().linear_threshold(&seed_nodes, threshold, weighted, max_steps, seed));
+    }
+
+    #[pyo3(signature = (k, model=CascadeModel::IndependentCascade, method=SeedSelection::CelfPlusPlus, n_simulations=100, probability=0.1, weighted=false, seed=None))]
+    pub fn influence_maximization(
+        &self,
+        k: usize,
+        model: CascadeModel,
+        method: SeedSelection,
+        n_simulations: usize,
+        probability: f64,
+        weighted: bool,
+        seed: Option<u64>
+    ) -> PyResult<(Vec<String>, f64)> {
+        check_probability("probability", probability)?;
+
+        return Ok(self.inner.borrow().influence_maximization(k, model, method, n_simulations, probability, weighted, seed));
+    }
+
     #[pyo3(signature = (layout=Layout::Random, override_positions=false, style=None))]
     pub fn output_svg(&mut self, layout: Layout, override_positions: bool, style: Option<GraphStyle>) -> String {
         let graph_style = match style {
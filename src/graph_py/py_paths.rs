@@ -0,0 +1,20 @@
+use pyo3::prelude::*;
+use crate::algorithms::paths::SimplePaths;
+use pyo3_stub_gen::derive::gen_stub_pyclass;
+
+#[gen_stub_pyclass]
+#[pyclass(unsendable, module="netfog")]
+pub struct SimplePathIterator {
+    pub inner: SimplePaths,
+}
+
+#[pymethods]
+impl SimplePathIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        return slf;
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Vec<String>> {
+        return slf.inner.next();
+    }
+}
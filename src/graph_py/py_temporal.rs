@@ -0,0 +1,32 @@
+use pyo3::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::graph_core::graph::_Graph;
+use crate::graph_py::py_graph::Graph;
+use crate::algorithms::temporal::{window, WindowSchedule};
+use pyo3_stub_gen::derive::gen_stub_pyclass;
+
+/*
+ * Lazy iterator over the sliding windows of a temporal graph as
+ * (start, end, Graph) tuples. Each window graph is built when it is reached.
+ */
+#[gen_stub_pyclass]
+#[pyclass(unsendable, module="netfog")]
+pub struct WindowIterator {
+    pub graph: Rc<RefCell<_Graph>>,
+    pub schedule: WindowSchedule
+}
+
+#[pymethods]
+impl WindowIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        return slf;
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(f64, f64, Graph)> {
+        let (start, end) = slf.schedule.next()?;
+        let graph = window(&slf.graph.borrow(), start, end);
+
+        return Some((start, end, Graph { inner: Rc::new(RefCell::new(graph)) }));
+    }
+}
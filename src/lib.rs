@@ -5,15 +5,39 @@ pub mod svg_creation;
 pub mod layout;
 pub mod graph_py;
 pub mod external_apis;
+pub mod algorithms;
+pub mod simulations;
 
 pub use graph_core::node::_Node;
 pub use file_writer_core::file_writer::{HtmlWriter, Writeable};
 
 pub use graph_core::graph::_Graph;
 pub use graph_core::graph::{ConnectionProperty};
+pub use graph_core::edges::{EdgeRef, Edges};
+pub use algorithms::dag::CycleError;
+pub use algorithms::main_path::TraversalWeight;
+pub use algorithms::bipartite::{NotBipartiteError, ProjectionMethod};
+pub use algorithms::motifs::MotifCensus;
+pub use algorithms::null_models::NullModel;
+pub use algorithms::link_prediction::LinkPredictor;
+pub use algorithms::similarity::SimilarityMethod;
+pub use algorithms::paths::WeightInterpretation;
+pub use algorithms::robustness::{RemovalStrategy, RemovalTarget, RobustnessResult};
+pub use algorithms::rewiring::Significance;
+pub use algorithms::quotient::EdgeAggregation;
+pub use algorithms::node_metrics::{NodeMetrics, UnknownMetricError};
+pub use algorithms::summary::GraphSummary;
+pub use algorithms::power_law::{DegreeType, LikelihoodRatio, NotEnoughDataError, PowerLawFit};
+pub use algorithms::backbone::BackboneMethod;
+pub use algorithms::traversal::{TraversalVisitor, VisitAction};
+pub use simulations::diffusion::SimulationResult;
+pub use simulations::influence::{CascadeModel, SeedSelection};
 
 pub use graph_py::py_graph::Graph;
 pub use graph_py::py_node::Node;
+pub use graph_py::py_paths::SimplePathIterator;
+pub use graph_py::py_edges::EdgeIterator;
+pub use graph_py::py_temporal::WindowIterator;
 use pyo3::prelude::*;
 use pyo3_stub_gen::*;
 
@@ -29,6 +53,28 @@ fn netfog(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Layout>()?;
     m.add_class::<GraphStyle>()?;
     m.add_class::<OpenAlexGraphType>()?;
+    m.add_class::<TraversalWeight>()?;
+    m.add_class::<ProjectionMethod>()?;
+    m.add_class::<MotifCensus>()?;
+    m.add_class::<NullModel>()?;
+    m.add_class::<LinkPredictor>()?;
+    m.add_class::<SimilarityMethod>()?;
+    m.add_class::<SimplePathIterator>()?;
+    m.add_class::<EdgeIterator>()?;
+    m.add_class::<WindowIterator>()?;
+    m.add_class::<WeightInterpretation>()?;
+    m.add_class::<RemovalTarget>()?;
+    m.add_class::<RemovalStrategy>()?;
+    m.add_class::<RobustnessResult>()?;
+    m.add_class::<EdgeAggregation>()?;
+    m.add_class::<DegreeType>()?;
+    m.add_class::<LikelihoodRatio>()?;
+    m.add_class::<PowerLawFit>()?;
+    m.add_class::<BackboneMethod>()?;
+    m.add_class::<VisitAction>()?;
+    m.add_class::<SimulationResult>()?;
+    m.add_class::<CascadeModel>()?;
+    m.add_class::<SeedSelection>()?;
     Ok(())
 }
 
@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rand::rngs::StdRng;
+use rand::Rng;
+use crate::_Graph;
+use crate::algorithms::adjacency::{label_positions, node_labels, weighted_adjacency};
+use crate::algorithms::null_models::seeded_rng;
+
+/*
+ * State counts after each step (step 0 holds the seed nodes) and the step
+ * at which each node was first infected. Cascade models only use the
+ * 'susceptible' (inactive) and 'infected' (active) columns.
+ */
+#[pyclass(get_all)]
+#[derive(Clone, Debug)]
+pub struct SimulationResult {
+    pub step: Vec<usize>,
+    pub susceptible: Vec<usize>,
+    pub infected: Vec<usize>,
+    pub recovered: Vec<usize>,
+    pub infection_times: HashMap<String, usize>
+}
+
+#[pymethods]
+impl SimulationResult {
+    /*
+     * Per-step columns as a dict of lists, ready for pandas.DataFrame or plotting.
+     */
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let table = PyDict::new(py);
+        table.set_item("step", self.step.clone())?;
+        table.set_item("susceptible", self.susceptible.clone())?;
+        table.set_item("infected", self.infected.clone())?;
+        table.set_item("recovered", self.recovered.clone())?;
+
+        return Ok(table);
+    }
+
+    /*
+     * Number of nodes that were infected at some point.
+     */
+    pub fn total_infected(&self) -> usize {
+        return self.infection_times.len();
+    }
+}
+
+/*
+ * Out-neighbours of every node with the summed weight of the connections
+ * leading to them. Directed connections spread in their direction,
+ * undirected ones both ways; self-loops are ignored.
+ */
+pub fn spreading_view(graph: &_Graph) -> Vec<Vec<(usize, f64)>> {
+    let mut spreading: Vec<Vec<(usize, f64)>> = Vec::new();
+
+    for (i, adj) in weighted_adjacency(graph).into_iter().enumerate() {
+        let mut merged: Vec<(usize, f64)> = Vec::new();
+
+        for (j, weight) in adj {
+            if i == j {
+                continue;
+            }
+
+            match merged.iter_mut().find(|(k, _)| *k == j) {
+                Some(entry) => entry.1 += weight as f64,
+                None => merged.push((j, weight as f64)),
+            }
+        }
+
+        merged.sort_by(|a, b| a.0.cmp(&b.0));
+        spreading.push(merged);
+    }
+
+    return spreading;
+}
+
+/*
+ * Share of the incoming influence of the target carried by each connection:
+ * weight / total incoming weight, or 1 / in-degree when unweighted. Targets
+ * whose incoming weights sum to 0 get a share of 0.
+ */
+pub fn incoming_shares(spreading: &Vec<Vec<(usize, f64)>>, weighted: bool) -> Vec<Vec<(usize, f64)>> {
+    let mut incoming: Vec<f64> = vec![0.; spreading.len()];
+    for adj in spreading {
+        for (j, weight) in adj {
+            incoming[*j] += if weighted { *weight } else { 1. };
+        }
+    }
+
+    let shares: Vec<Vec<(usize, f64)>> = spreading
+        .iter()
+        .map(|adj| {
+            adj.iter()
+                .map(|(j, weight)| {
+                    if incoming[*j] == 0. {
+                        return (*j, 0.);
+                    }
+                    (*j, if weighted { *weight } else { 1. } / incoming[*j])
+                })
+                .collect()
+        })
+        .collect();
+
+    return shares;
+}
+
+/*
+ * Activation probability of every connection for the independent cascade:
+ * the same 'probability' for all of them, or the weighted cascade rule
+ * (share of the incoming weight of the target) when weighted.
+ */
+pub fn cascade_probabilities(spreading: &Vec<Vec<(usize, f64)>>, probability: f64, weighted: bool) -> Vec<Vec<(usize, f64)>> {
+    if weighted {
+        return incoming_shares(spreading, true);
+    }
+
+    return spreading
+        .iter()
+        .map(|adj| adj.iter().map(|(j, _)| (*j, probability)).collect())
+        .collect();
+}
+
+pub fn seed_positions(graph: &_Graph, seed_nodes: &Vec<String>) -> Vec<usize> {
+    let positions = label_positions(graph);
+    let mut seeds: Vec<usize> = seed_nodes
+        .iter()
+        .map(|label| *positions.get(label).expect("Node not found"))
+        .collect();
+    seeds.sort();
+    seeds.dedup();
+
+    return seeds;
+}
+
+/*
+ * Each newly activated node gets a single chance to activate each inactive
+ * out-neighbour. Returns the activation step of every node.
+ */
+pub fn run_independent_cascade(
+    probabilities: &Vec<Vec<(usize, f64)>>,
+    seeds: &Vec<usize>,
+    max_steps: Option<usize>,
+    rng: &mut StdRng
+) -> Vec<Option<usize>> {
+    let mut activated: Vec<Option<usize>> = vec![None; probabilities.len()];
+    for s in seeds {
+        activated[*s] = Some(0);
+    }
+
+    let mut frontier: Vec<usize> = seeds.clone();
+    let mut step = 0;
+
+    while !frontier.is_empty() && max_steps.map_or(true, |m| step < m) {
+        step += 1;
+        let mut next: Vec<usize> = Vec::new();
+
+        for v in frontier {
+            for (w, p) in &probabilities[v] {
+                if activated[*w].is_none() && rng.gen_bool(p.clamp(0., 1.)) {
+                    activated[*w] = Some(step);
+                    next.push(*w);
+                }
+            }
+        }
+
+        frontier = next;
+    }
+
+    return activated;
+}
+
+/*
+ * A node becomes active once the summed influence of its active
+ * in-neighbours reaches its threshold. Thresholds are drawn uniformly from
+ * [0, 1) unless a fixed one is given.
+ */
+pub fn run_linear_threshold(
+    influences: &Vec<Vec<(usize, f64)>>,
+    seeds: &Vec<usize>,
+    threshold: Option<f64>,
+    max_steps: Option<usize>,
+    rng: &mut StdRng
+) -> Vec<Option<usize>> {
+    let size = influences.len();
+    let thresholds: Vec<f64> = (0..size).map(|_| threshold.unwrap_or_else(|| rng.gen_range(0.0..1.0))).collect();
+    let mut received: Vec<f64> = vec![0.; size];
+    let mut activated: Vec<Option<usize>> = vec![None; size];
+    for s in seeds {
+        activated[*s] = Some(0);
+    }
+
+    let mut frontier: Vec<usize> = seeds.clone();
+    let mut step = 0;
+
+    while !frontier.is_empty() && max_steps.map_or(true, |m| step < m) {
+        step += 1;
+        let mut next: Vec<usize> = Vec::new();
+
+        for v in &frontier {
+            for (w, b) in &influences[*v] {
+                received[*w] += b;
+            }
+        }
+
+        for v in frontier {
+            for (w, _) in &influences[v] {
+                if activated[*w].is_none() && received[*w] >= thresholds[*w] {
+                    activated[*w] = Some(step);
+                    next.push(*w);
+                }
+            }
+        }
+
+        frontier = next;
+    }
+
+    return activated;
+}
+
+fn cascade_result(labels: &Vec<String>, activated: &Vec<Option<usize>>) -> SimulationResult {
+    let size = labels.len();
+    let last_step = activated.iter().filter_map(|t| *t).max().unwrap_or(0);
+    let mut result = SimulationResult {
+        step: Vec::new(),
+        susceptible: Vec::new(),
+        infected: Vec::new(),
+        recovered: Vec::new(),
+        infection_times: HashMap::new()
+    };
+
+    for step in 0..=last_step {
+        let active = activated.iter().filter(|t| t.map_or(false, |t| t <= step)).count();
+        result.step.push(step);
+        result.susceptible.push(size - active);
+        result.infected.push(active);
+        result.recovered.push(0);
+    }
+
+    for (v, time) in activated.iter().enumerate() {
+        if let Some(t) = time {
+            result.infection_times.insert(labels[v].clone(), *t);
+        }
+    }
+
+    return result;
+}
+
+pub fn independent_cascade(
+    graph: &_Graph,
+    seed_nodes: &Vec<String>,
+    probability: f64,
+    weighted: bool,
+    max_steps: Option<usize>,
+    seed: Option<u64>
+) -> SimulationResult {
+    let mut rng = seeded_rng(seed);
+    let probabilities = cascade_probabilities(&spreading_view(graph), probability, weighted);
+    let activated = run_independent_cascade(&probabilities, &seed_positions(graph, seed_nodes), max_steps, &mut rng);
+
+    return cascade_result(&node_labels(graph), &activated);
+}
+
+pub fn linear_threshold(
+    graph: &_Graph,
+    seed_nodes: &Vec<String>,
+    threshold: Option<f64>,
+    weighted: bool,
+    max_steps: Option<usize>,
+    seed: Option<u64>
+) -> SimulationResult {
+    let mut rng = seeded_rng(seed);
+    let influences = incoming_shares(&spreading_view(graph), weighted);
+    let activated = run_linear_threshold(&influences, &seed_positions(graph, seed_nodes), threshold, max_steps, &mut rng);
+
+    return cascade_result(&node_labels(graph), &activated);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Susceptible,
+    Infected,
+    Recovered
+}
+
+/*
+ * Synchronous discrete-time epidemic: at every step each infected node
+ * infects each susceptible out-neighbour with probability 'beta' (or
+ * 1 - (1 - beta)^weight when weighted, the weight acting as a number of
+ * contacts), then recovers with probability 'gamma'. Recovered nodes are
+ * immune (SIR) or susceptible again (SIS). Stops after 'max_steps' steps or
+ * when nobody is infected.
+ */
+fn epidemic(
+    graph: &_Graph,
+    seed_nodes: &Vec<String>,
+    beta: f64,
+    gamma: f64,
+    max_steps: usize,
+    weighted: bool,
+    immunity: bool,
+    seed: Option<u64>
+) -> SimulationResult {
+    let mut rng = seeded_rng(seed);
+    let labels = node_labels(graph);
+    let spreading = spreading_view(graph);
+    let size = spreading.len();
+
+    let transmission: Vec<Vec<(usize, f64)>> = spreading
+        .iter()
+        .map(|adj| {
+            adj.iter()
+                .map(|(j, weight)| (*j, if weighted { 1. - (1. - beta).powf(*weight) } else { beta }))
+                .collect()
+        })
+        .collect();
+
+    let mut state: Vec<State> = vec![State::Susceptible; size];
+    let mut infection_times: Vec<Option<usize>> = vec![None; size];
+    for s in seed_positions(graph, seed_nodes) {
+        state[s] = State::Infected;
+        infection_times[s] = Some(0);
+    }
+
+    let mut result = SimulationResult {
+        step: Vec::new(),
+        susceptible: Vec::new(),
+        infected: Vec::new(),
+        recovered: Vec::new(),
+        infection_times: HashMap::new()
+    };
+
+    let record = |result: &mut SimulationResult, step: usize, state: &Vec<State>| {
+        result.step.push(step);
+        result.susceptible.push(state.iter().filter(|s| **s == State::Susceptible).count());
+        result.infected.push(state.iter().filter(|s| **s == State::Infected).count());
+        result.recovered.push(state.iter().filter(|s| **s == State::Recovered).count());
+    };
+
+    record(&mut result, 0, &state);
+
+    for step in 1..=max_steps {
+        if !state.contains(&State::Infected) {
+            break;
+        }
+
+        let mut next = state.clone();
+
+        for v in 0..size {
+            if state[v] != State::Infected {
+                continue;
+            }
+
+            for (w, p) in &transmission[v] {
+                if state[*w] == State::Susceptible && next[*w] == State::Susceptible && rng.gen_bool(p.clamp(0., 1.)) {
+                    next[*w] = State::Infected;
+                    if infection_times[*w].is_none() {
+                        infection_times[*w] = Some(step);
+                    }
+                }
+            }
+
+            if rng.gen_bool(gamma.clamp(0., 1.)) {
+                next[v] = if immunity { State::Recovered } else { State::Susceptible };
+            }
+        }
+
+        state = next;
+        record(&mut result, step, &state);
+    }
+
+    for (v, time) in infection_times.iter().enumerate() {
+        if let Some(t) = time {
+            result.infection_times.insert(labels[v].clone(), *t);
+        }
+    }
+
+    return result;
+}
+
+pub fn sir(
+    graph: &_Graph,
+    seed_nodes: &Vec<String>,
+    beta: f64,
+    gamma: f64,
+    max_steps: usize,
+    weighted: bool,
+    seed: Option<u64>
+) -> SimulationResult {
+    return epidemic(graph, seed_nodes, beta, gamma, max_steps, weighted, true, seed);
+}
+
+pub fn sis(
+    graph: &_Graph,
+    seed_nodes: &Vec<String>,
+    beta: f64,
+    gamma: f64,
+    max_steps: usize,
+    weighted: bool,
+    seed: Option<u64>
+) -> SimulationResult {
+    return epidemic(graph, seed_nodes, beta, gamma, max_steps, weighted, false, seed);
+}
@@ -0,0 +1,284 @@
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use crate::_Graph;
+use crate::algorithms::adjacency::{neighbours, node_labels};
+use crate::algorithms::null_models::seeded_rng;
+use crate::simulations::diffusion::{
+    cascade_probabilities, incoming_shares, run_independent_cascade, run_linear_threshold, spreading_view
+};
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum CascadeModel {
+    IndependentCascade,
+    LinearThreshold
+}
+
+#[pyclass(eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum SeedSelection {
+    Greedy,
+    Celf,
+    CelfPlusPlus,
+    DegreeDiscount
+}
+
+/*
+ * Monte Carlo estimate of the expected number of nodes activated by a seed
+ * set, for one cascade model on a fixed graph.
+ */
+struct SpreadEstimator {
+    model: CascadeModel,
+    edges: Vec<Vec<(usize, f64)>>,
+    n_simulations: usize,
+    rng: StdRng
+}
+
+impl SpreadEstimator {
+    fn spread(&mut self, seeds: &Vec<usize>) -> f64 {
+        if seeds.is_empty() || self.n_simulations == 0 {
+            return seeds.len() as f64;
+        }
+
+        let mut total = 0;
+        for _ in 0..self.n_simulations {
+            let activated = match self.model {
+                CascadeModel::IndependentCascade => run_independent_cascade(&self.edges, seeds, None, &mut self.rng),
+                CascadeModel::LinearThreshold => run_linear_threshold(&self.edges, seeds, None, None, &mut self.rng),
+            };
+            total += activated.iter().filter(|t| t.is_some()).count();
+        }
+
+        return total as f64 / self.n_simulations as f64;
+    }
+
+    fn gain(&mut self, seeds: &Vec<usize>, base: f64, candidates: &[usize]) -> f64 {
+        let mut extended = seeds.clone();
+        extended.extend_from_slice(candidates);
+
+        return self.spread(&extended) - base;
+    }
+}
+
+fn greedy(estimator: &mut SpreadEstimator, size: usize, k: usize) -> Vec<usize> {
+    let mut seeds: Vec<usize> = Vec::new();
+    let mut base = 0.;
+
+    while seeds.len() < k.min(size) {
+        let mut best: Option<(usize, f64)> = None;
+
+        for v in 0..size {
+            if seeds.contains(&v) {
+                continue;
+            }
+
+            let gain = estimator.gain(&seeds, base, &[v]);
+            if best.map_or(true, |(_, g)| gain > g) {
+                best = Some((v, gain));
+            }
+        }
+
+        let (v, gain) = best.unwrap();
+        seeds.push(v);
+        base += gain;
+    }
+
+    return seeds;
+}
+
+/*
+ * CELF (Leskovec et al.): by submodularity a marginal gain can only shrink
+ * as the seed set grows, so gains are re-evaluated lazily, only for the
+ * node currently on top, until the top one is up to date.
+ */
+fn celf(estimator: &mut SpreadEstimator, size: usize, k: usize) -> Vec<usize> {
+    // (node, marginal gain, seed set size it was computed for)
+    let mut queue: Vec<(usize, f64, usize)> = (0..size).map(|v| (v, estimator.spread(&vec![v]), 0)).collect();
+    let mut seeds: Vec<usize> = Vec::new();
+    let mut base = 0.;
+
+    while seeds.len() < k.min(size) {
+        let top = top_entry(&queue, |e| e.1);
+        let (v, gain, computed_for) = queue[top];
+
+        if computed_for == seeds.len() {
+            seeds.push(v);
+            base += gain;
+            queue.swap_remove(top);
+            continue;
+        }
+
+        queue[top] = (v, estimator.gain(&seeds, base, &[v]), seeds.len());
+    }
+
+    return seeds;
+}
+
+struct CelfPlusPlusEntry {
+    node: usize,
+    // Gain with respect to the current seed set
+    mg1: f64,
+    // Best node seen when mg1 was computed, and gain with respect to seeds + prev_best
+    prev_best: Option<usize>,
+    mg2: f64,
+    flag: usize
+}
+
+/*
+ * CELF++ (Goyal et al.): when a gain is evaluated, the gain with respect to
+ * the seeds plus the current best candidate is evaluated too, so if that
+ * candidate is picked next the node does not need a new evaluation.
+ */
+fn celf_plus_plus(estimator: &mut SpreadEstimator, size: usize, k: usize) -> Vec<usize> {
+    let mut queue: Vec<CelfPlusPlusEntry> = Vec::new();
+    let mut cur_best: Option<(usize, f64)> = None;
+
+    for v in 0..size {
+        let mg1 = estimator.spread(&vec![v]);
+        let mg2 = match cur_best {
+            Some((b, b_spread)) => estimator.gain(&vec![b], b_spread, &[v]),
+            None => mg1,
+        };
+
+        queue.push(CelfPlusPlusEntry { node: v, mg1: mg1, prev_best: cur_best.map(|(b, _)| b), mg2: mg2, flag: 0 });
+
+        if cur_best.map_or(true, |(_, g)| mg1 > g) {
+            cur_best = Some((v, mg1));
+        }
+    }
+
+    let mut seeds: Vec<usize> = Vec::new();
+    let mut base = 0.;
+    let mut last_seed: Option<usize> = None;
+    cur_best = None;
+
+    while seeds.len() < k.min(size) {
+        let top = top_entry(&queue, |e| e.mg1);
+        let entry = &queue[top];
+        let v = entry.node;
+
+        if entry.flag == seeds.len() {
+            seeds.push(v);
+            base += entry.mg1;
+            last_seed = Some(v);
+            cur_best = None;
+            queue.swap_remove(top);
+            continue;
+        }
+
+        let (mg1, prev_best, mg2) = if entry.prev_best.is_some() && entry.prev_best == last_seed && entry.flag + 1 == seeds.len() {
+            (entry.mg2, entry.prev_best, entry.mg2)
+        } else {
+            let mg1 = estimator.gain(&seeds, base, &[v]);
+            let mg2 = match cur_best {
+                Some((b, b_gain)) if b != v => estimator.gain(&seeds, base + b_gain, &[b, v]),
+                _ => mg1,
+            };
+            (mg1, cur_best.map(|(b, _)| b), mg2)
+        };
+
+        queue[top] = CelfPlusPlusEntry { node: v, mg1: mg1, prev_best: prev_best, mg2: mg2, flag: seeds.len() };
+
+        if cur_best.map_or(true, |(_, g)| mg1 > g) {
+            cur_best = Some((v, mg1));
+        }
+    }
+
+    return seeds;
+}
+
+fn top_entry<T>(queue: &Vec<T>, gain: impl Fn(&T) -> f64) -> usize {
+    let mut top = 0;
+    for i in 1..queue.len() {
+        if gain(&queue[i]) > gain(&queue[top]) {
+            top = i;
+        }
+    }
+
+    return top;
+}
+
+/*
+ * Degree discount heuristic (Chen et al.): picking a node discounts the
+ * degree of its neighbours, dd(v) = d(v) - 2 t(v) - (d(v) - t(v)) t(v) p,
+ * where t(v) is the number of neighbours of v already selected. It uses
+ * the undirected degree and does not run any simulation.
+ */
+fn degree_discount(adjacency: &Vec<Vec<usize>>, k: usize, probability: f64) -> Vec<usize> {
+    let size = adjacency.len();
+    let degree: Vec<f64> = adjacency.iter().map(|adj| adj.len() as f64).collect();
+    let mut discounted: Vec<f64> = degree.clone();
+    let mut selected_neighbours: Vec<f64> = vec![0.; size];
+    let mut selected: Vec<bool> = vec![false; size];
+    let mut seeds: Vec<usize> = Vec::new();
+
+    while seeds.len() < k.min(size) {
+        let v = (0..size)
+            .filter(|v| !selected[*v])
+            .fold(None, |best: Option<usize>, v| match best {
+                Some(b) if discounted[b] >= discounted[v] => Some(b),
+                _ => Some(v),
+            })
+            .unwrap();
+
+        selected[v] = true;
+        seeds.push(v);
+
+        for w in &adjacency[v] {
+            if selected[*w] {
+                continue;
+            }
+
+            selected_neighbours[*w] += 1.;
+            let t = selected_neighbours[*w];
+            discounted[*w] = degree[*w] - 2. * t - (degree[*w] - t) * t * probability;
+        }
+    }
+
+    return seeds;
+}
+
+/*
+ * Selects 'k' seed nodes maximising the expected spread of the cascade
+ * model, estimated with 'n_simulations' Monte Carlo runs per evaluation.
+ * 'probability' and 'weighted' set the edge probabilities as in
+ * independent_cascade and linear_threshold. Returns the seeds in selection
+ * order and the estimated spread of the whole set.
+ */
+pub fn influence_maximization(
+    graph: &_Graph,
+    k: usize,
+    model: &CascadeModel,
+    method: &SeedSelection,
+    n_simulations: usize,
+    probability: f64,
+    weighted: bool,
+    seed: Option<u64>
+) -> (Vec<String>, f64) {
+    let labels = node_labels(graph);
+    let spreading = spreading_view(graph);
+    let size = spreading.len();
+
+    let edges = match model {
+        CascadeModel::IndependentCascade => cascade_probabilities(&spreading, probability, weighted),
+        CascadeModel::LinearThreshold => incoming_shares(&spreading, weighted),
+    };
+
+    let mut estimator = SpreadEstimator {
+        model: model.clone(),
+        edges: edges,
+        n_simulations: n_simulations,
+        rng: seeded_rng(seed)
+    };
+
+    let seeds = match method {
+        SeedSelection::Greedy => greedy(&mut estimator, size, k),
+        SeedSelection::Celf => celf(&mut estimator, size, k),
+        SeedSelection::CelfPlusPlus => celf_plus_plus(&mut estimator, size, k),
+        SeedSelection::DegreeDiscount => degree_discount(&neighbours(graph), k, probability),
+    };
+
+    let spread = estimator.spread(&seeds);
+
+    return (seeds.into_iter().map(|v| labels[v].clone()).collect(), spread);
+}
@@ -0,0 +1,2 @@
+pub mod diffusion;
+pub mod influence;
@@ -1,9 +1,7 @@
-use crate::_Node;
-use crate::graph_core::graph::{ConnectionsList, ConnectionProperty};
+use crate::{_Graph, _Node};
 use crate::layout::layout::{Layout, get_layout_function};
 use crate::layout::style::{GraphStyle, get_line_width};
 use std::cell::RefCell;
-use std::collections::HashMap;
 use std::f64;
 use std::rc::Rc;
 use std::fmt::{self, format};
@@ -11,21 +9,6 @@ use std::fmt::{self, format};
 const LABEL_OFFSET: f64 = 35.;
 
 
-fn create_node_hashmap(nodes: &Vec<Rc<RefCell<_Node>>>) -> HashMap<String, Rc<RefCell<_Node>>> {
-    let mut node_map: HashMap<String, Rc<RefCell<_Node>>> = HashMap::new();
-
-    for n in nodes {
-        let node_handle = Rc::clone(n);
-        let label = node_handle.borrow().label.clone();
-
-
-        node_map.insert(label, node_handle);
-    }
-
-
-    return node_map;
-}
-
 enum AtributeValue {
     Text(String),
     Decimal(f64),
@@ -244,8 +227,7 @@ impl Svg {
 
     fn draw_lines(
         &mut self,
-        connections: &ConnectionsList,
-        node_map: HashMap<String, Rc<RefCell<_Node>>>,
+        graph: &_Graph,
         line_color: &str,
         min_weight: f32,
         max_weight: f32,
@@ -255,23 +237,9 @@ impl Svg {
     ) {
         let mut marker_only_lines: Vec<Element> = Vec::new();
 
-        for conn in connections {
-            let mut from_name = None;
-            let mut to_name = None;
-            let mut directed = false;
-            let mut weight = 0.;
-
-            for property in conn.values() {
-                match property {
-                    ConnectionProperty::From(name) => from_name = Some(name),
-                    ConnectionProperty::To(name) => to_name = Some(name),
-                    ConnectionProperty::Directed(bool) => directed = *bool,
-                    ConnectionProperty::Weight(w) => weight = *w,
-                    _ => {}
-                }
-            }
-            let from = node_map[from_name.unwrap()].borrow();
-            let to = node_map[to_name.unwrap()].borrow();
+        for edge in graph.edges() {
+            let from = graph.nodes[edge.from].borrow();
+            let to = graph.nodes[edge.to].borrow();
 
             let line_pos = ElementPostion {
                 x1: from.x.unwrap(),
@@ -281,10 +249,10 @@ impl Svg {
             };
             let mut line_width = 1.;
             if dynamic_lines {
-                line_width = get_line_width(weight, min_weight, max_weight, min_width as f32, max_width as f32);
+                line_width = get_line_width(edge.weight, min_weight, max_weight, min_width as f32, max_width as f32);
             }
 
-            let marker_line = self.add_line(line_pos, from.index.unwrap(), to.index.unwrap(), directed, line_color, line_width);
+            let marker_line = self.add_line(line_pos, from.index.unwrap(), to.index.unwrap(), edge.directed, line_color, line_width);
             if !marker_line.is_none() {
                 marker_only_lines.push(marker_line.unwrap());
             }
@@ -300,30 +268,26 @@ impl Svg {
 
     fn draw_graph(
         &mut self,
-        nodes: &Vec<Rc<RefCell<_Node>>>,
-        connections: &ConnectionsList,
+        graph: &_Graph,
         layout: Layout,
         positions_set: bool,
         override_positions: bool,
-        node_map: HashMap<String, Rc<RefCell<_Node>>>,
         style: GraphStyle
     ) {
         if !positions_set || override_positions {
             let layout_func = get_layout_function(layout);
-            layout_func(&nodes);
+            layout_func(&graph.nodes);
         }
 
         let min_weight = 1.;
-        let max_weight = connections.iter()
-            .filter_map(|conn| conn.get("Weight"))
-            .filter_map(|prop| if let ConnectionProperty::Weight(w) = prop { Some(w) } else { None })
+        let max_weight = graph.edges()
+            .map(|edge| edge.weight)
             .max_by(|a, b| a.total_cmp(b))
-            .copied()
             .unwrap_or(1.0);
 
         self.add_arrow_def(style.marker_svg, style.marker_fill, style.marker_width, style.marker_height);
-        self.draw_lines(connections, node_map, &style.line_color, min_weight, max_weight, style.line_min_width, style.line_max_width, style.dynamic_line_size);
-        self.draw_nodes(nodes, &style.node_color, &style.node_border, style.node_radius);
+        self.draw_lines(graph, &style.line_color, min_weight, max_weight, style.line_min_width, style.line_max_width, style.dynamic_line_size);
+        self.draw_nodes(&graph.nodes, &style.node_color, &style.node_border, style.node_radius);
 
     }
 
@@ -405,19 +369,13 @@ impl Svg {
 
     pub fn get_svg(
         &mut self,
-        nodes: &Vec<Rc<RefCell<_Node>>>,
-        connections: &ConnectionsList,
+        graph: &_Graph,
         layout: Layout,
         positions_set: bool,
         override_positions: bool,
         style: GraphStyle
     ) -> String {
-        // TODO -> Remove this workaround
-        // For now, we store the x,y by label
-        // But in future this will be changed, so we get a better performance
-        let node_map = create_node_hashmap(nodes);
-
-        self.draw_graph(nodes, connections, layout, positions_set, override_positions, node_map, style);
+        self.draw_graph(graph, layout, positions_set, override_positions, style);
 
         let svg = self.write_svg();
 
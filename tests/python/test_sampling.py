@@ -0,0 +1,32 @@
+import math
+import unittest
+
+from netfog import Graph
+
+
+def ring(size):
+    graph = Graph()
+    for i in range(size):
+        graph.add_node(str(i))
+    for i in range(size):
+        graph.create_connection(str(i), str((i + 1) % size), 1., False)
+    return graph
+
+
+class TestSamplingProbabilities(unittest.TestCase):
+    def test_invalid_probabilities(self):
+        graph = ring(10)
+        for value in [-0.1, 1., math.nan]:
+            with self.assertRaises(ValueError):
+                graph.random_walk_sample(5, restart_probability=value)
+            with self.assertRaises(ValueError):
+                graph.forest_fire_sample(5, burn_probability=value)
+
+    def test_valid_probabilities(self):
+        graph = ring(10)
+        self.assertEqual(5, graph.random_walk_sample(5, restart_probability=0., seed=1).get_node_count())
+        self.assertEqual(5, graph.forest_fire_sample(5, burn_probability=0.995, seed=1).get_node_count())
+
+
+if __name__ == "__main__":
+    unittest.main()
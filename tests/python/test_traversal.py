@@ -0,0 +1,41 @@
+import unittest
+
+from netfog import Graph, VisitAction
+
+
+def chain():
+    graph = Graph()
+    for label in ["A", "B", "C", "D"]:
+        graph.add_node(label)
+    graph.create_connection("A", "B", 1., True)
+    graph.create_connection("B", "C", 1., True)
+    graph.create_connection("C", "D", 1., True)
+    return graph
+
+
+class TestTraversalCallbacks(unittest.TestCase):
+    def test_callbacks_can_change_the_graph(self):
+        for traversal in ["dfs", "bfs"]:
+            graph = chain()
+            degrees = []
+
+            def on_discover(label, depth):
+                degrees.append(graph.compute_degrees(label))
+                graph.get_edge_count()
+                graph.add_node(label + "'")
+
+            order = getattr(graph, traversal)("A", on_discover=on_discover)
+
+            self.assertEqual(["A", "B", "C", "D"], order)
+            self.assertEqual(4, len(degrees))
+            self.assertEqual(8, graph.get_node_count())
+
+    def test_stop_from_callback(self):
+        graph = chain()
+        order = graph.dfs("A", on_discover=lambda label, depth: VisitAction.Stop if label == "B" else None)
+
+        self.assertEqual(["A", "B"], order)
+
+
+if __name__ == "__main__":
+    unittest.main()
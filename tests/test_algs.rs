@@ -1,4 +1,7 @@
+use std::collections::{HashMap, HashSet};
 use netfog::*;
+use netfog::graph_core::node::Connection;
+use approx::assert_abs_diff_eq;
 
 #[test]
 fn test_dfs() {
@@ -60,6 +63,94 @@ fn test_bfs() {
     assert_eq!(expected_order, graph.bfs("0"));
 }
 
+#[derive(Default)]
+struct EventLog {
+    events: Vec<String>,
+    stop_at: Option<String>,
+    prune_at: Option<String>
+}
+
+impl TraversalVisitor for EventLog {
+    fn discover(&mut self, node: &str, depth: usize) -> VisitAction {
+        self.events.push(format!("discover {} {}", node, depth));
+        if self.stop_at.as_deref() == Some(node) {
+            return VisitAction::Stop;
+        }
+        if self.prune_at.as_deref() == Some(node) {
+            return VisitAction::Prune;
+        }
+        return VisitAction::Continue;
+    }
+
+    fn finish(&mut self, node: &str, _depth: usize) -> VisitAction {
+        self.events.push(format!("finish {}", node));
+        return VisitAction::Continue;
+    }
+
+    fn tree_edge(&mut self, from: &str, to: &str, _weight: f32) -> VisitAction {
+        self.events.push(format!("tree {}{}", from, to));
+        return VisitAction::Continue;
+    }
+
+    fn back_edge(&mut self, from: &str, to: &str, _weight: f32) -> VisitAction {
+        self.events.push(format!("back {}{}", from, to));
+        return VisitAction::Continue;
+    }
+
+    fn cross_edge(&mut self, from: &str, to: &str, _weight: f32) -> VisitAction {
+        self.events.push(format!("cross {}{}", from, to));
+        return VisitAction::Continue;
+    }
+}
+
+#[test]
+fn test_traversal_visitor() {
+    let mut graph = _Graph::default();
+    for label in ["A", "B", "C", "D"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("A".to_string(), "B".to_string(), 1., Some(true));
+    graph.create_connection("B".to_string(), "C".to_string(), 1., Some(true));
+    graph.create_connection("C".to_string(), "A".to_string(), 1., Some(true));
+    graph.create_connection("A".to_string(), "C".to_string(), 1., Some(true));
+    graph.create_connection("B".to_string(), "D".to_string(), 1., Some(true));
+
+    let mut log = EventLog::default();
+    graph.dfs_visit("A", &mut log, None);
+    assert_eq!(log.events, vec![
+        "discover A 0", "tree AB", "discover B 1", "tree BC", "discover C 2", "back CA", "finish C",
+        "tree BD", "discover D 2", "finish D", "finish B", "cross AC", "finish A"
+    ]);
+
+    let mut log = EventLog::default();
+    graph.bfs_visit("A", &mut log, None);
+    assert_eq!(log.events, vec![
+        "discover A 0", "tree AB", "discover B 1", "tree AC", "discover C 1", "finish A",
+        "cross BC", "tree BD", "discover D 2", "finish B", "back CA", "finish C", "finish D"
+    ]);
+
+    // Depth limit: nodes at depth 1 are not expanded
+    let mut log = EventLog::default();
+    graph.dfs_visit("A", &mut log, Some(1));
+    assert_eq!(log.events, vec![
+        "discover A 0", "tree AB", "discover B 1", "finish B", "tree AC", "discover C 1", "finish C", "finish A"
+    ]);
+
+    // Early termination
+    let mut log = EventLog { stop_at: Some("C".to_string()), ..Default::default() };
+    graph.dfs_visit("A", &mut log, None);
+    assert_eq!(log.events.last().unwrap(), "discover C 2");
+
+    // Pruning B hides D from the search
+    let mut log = EventLog { prune_at: Some("B".to_string()), ..Default::default() };
+    graph.bfs_visit("A", &mut log, None);
+    assert!(!log.events.iter().any(|e| e.contains('D')));
+
+    assert_eq!(graph.dfs("A"), vec!["A", "B", "C", "D"]);
+    assert_eq!(graph.bfs("A"), vec!["A", "B", "C", "D"]);
+}
+
 #[test]
 fn test_dijkstra() {
     let mut graph = _Graph::default();
@@ -81,7 +172,896 @@ fn test_dijkstra() {
     graph.create_connection("4".to_string(), "6".to_string(), 8., Some(false));
     graph.create_connection("5".to_string(), "6".to_string(), 2., Some(false));
 
-    let dijkstra_dists = graph.dijkstra("1");
+    let dijkstra_dists = graph.dijkstra("1", WeightInterpretation::Distance, None).unwrap();
 
     assert_eq!(dijkstra_dists["6"], 11.);
 }
+
+#[test]
+fn test_weight_interpretation() {
+    let graph = create_graph_from_edges(&["A", "B", "C", "D"], &[("A", "B", 10.), ("B", "C", 10.), ("A", "C", 1.)], false);
+
+    let distance = graph.dijkstra("A", WeightInterpretation::Distance, None).unwrap();
+    assert_abs_diff_eq!(1., distance["C"], epsilon = 1e-9);
+    assert!(distance["D"].is_infinite());
+
+    let strength = graph.dijkstra("A", WeightInterpretation::Strength, None).unwrap();
+    assert_abs_diff_eq!(0.2, strength["C"], epsilon = 1e-6);
+
+    let log = graph.dijkstra("A", WeightInterpretation::Log, None).unwrap();
+    assert_abs_diff_eq!(2. / 11f64.ln(), log["C"], epsilon = 1e-6);
+
+    let unweighted = graph.dijkstra("A", WeightInterpretation::Unweighted, None).unwrap();
+    assert_eq!(1., unweighted["C"]);
+    assert_eq!(1., unweighted["B"]);
+
+    let doubled = |w: f32| (w * 2.) as f64;
+    let custom = graph.dijkstra("A", WeightInterpretation::Distance, Some(&doubled)).unwrap();
+    assert_eq!(2., custom["C"]);
+
+    let paths = graph.k_shortest_paths("A", "C", 2, WeightInterpretation::Strength, None).unwrap();
+    assert_eq!(vec!["A", "B", "C"], paths[0].0);
+    assert_eq!(vec!["A", "C"], paths[1].0);
+
+    let negative = |w: f32| (5. - w) as f64;
+    let error = graph.dijkstra("A", WeightInterpretation::Distance, Some(&negative)).unwrap_err();
+    assert_eq!(-5., error.length);
+    let not_a_number = |_: f32| f64::NAN;
+    assert!(graph.k_shortest_paths("A", "C", 2, WeightInterpretation::Distance, Some(&not_a_number)).is_err());
+
+    let negative_weights = create_graph_from_edges(&["A", "B"], &[("A", "B", -1.)], false);
+    assert!(negative_weights.dijkstra("A", WeightInterpretation::Distance, None).is_err());
+    assert!(negative_weights.backbone(BackboneMethod::HighSalienceSkeleton, None).is_err());
+}
+
+fn create_citation_graph() -> _Graph {
+    let mut graph = _Graph::default();
+    for label in ["A", "B", "C", "D", "E"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("A".to_string(), "B".to_string(), 1., Some(true));
+    graph.create_connection("A".to_string(), "C".to_string(), 1., Some(true));
+    graph.create_connection("B".to_string(), "D".to_string(), 1., Some(true));
+    graph.create_connection("C".to_string(), "D".to_string(), 5., Some(true));
+    graph.create_connection("D".to_string(), "E".to_string(), 1., Some(true));
+
+    return graph;
+}
+
+#[test]
+fn test_topological_sort() {
+    let graph = create_citation_graph();
+
+    assert!(graph.is_dag());
+    assert_eq!(graph.find_cycle(), None);
+
+    let expected_order: Vec<String> = vec![
+        "A".to_string(),
+        "B".to_string(),
+        "C".to_string(),
+        "D".to_string(),
+        "E".to_string()
+    ];
+
+    assert_eq!(Ok(expected_order), graph.topological_sort());
+}
+
+#[test]
+fn test_topological_sort_with_cycle() {
+    let mut graph = create_citation_graph();
+    graph.create_connection("E".to_string(), "B".to_string(), 1., Some(true));
+
+    assert!(!graph.is_dag());
+
+    let error = graph.topological_sort().unwrap_err();
+    assert_eq!(vec!["B".to_string(), "D".to_string(), "E".to_string()], error.nodes);
+
+    let cycle = graph.find_cycle().unwrap();
+    assert_eq!(vec!["B".to_string(), "D".to_string(), "E".to_string()], cycle);
+}
+
+#[test]
+fn test_simple_cycles() {
+    let mut graph = _Graph::default();
+    for label in ["0", "1", "2"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("0".to_string(), "0".to_string(), 1., Some(true));
+    graph.create_connection("0".to_string(), "1".to_string(), 1., Some(true));
+    graph.create_connection("0".to_string(), "2".to_string(), 1., Some(true));
+    graph.create_connection("1".to_string(), "2".to_string(), 1., Some(true));
+    graph.create_connection("2".to_string(), "0".to_string(), 1., Some(true));
+    graph.create_connection("2".to_string(), "1".to_string(), 1., Some(true));
+
+    let mut cycles: Vec<Vec<String>> = graph.simple_cycles()
+        .into_iter()
+        .map(|mut cycle| {
+            let min_pos = cycle.iter().enumerate().min_by_key(|(_, l)| l.clone()).unwrap().0;
+            cycle.rotate_left(min_pos);
+            cycle
+        })
+        .collect();
+    cycles.sort();
+
+    let expected_cycles: Vec<Vec<String>> = vec![
+        vec!["0".to_string()],
+        vec!["0".to_string(), "1".to_string(), "2".to_string()],
+        vec!["0".to_string(), "2".to_string()],
+        vec!["1".to_string(), "2".to_string()],
+    ];
+
+    assert_eq!(expected_cycles, cycles);
+}
+
+#[test]
+fn test_dag_longest_path() {
+    let graph = create_citation_graph();
+
+    let expected_path: Vec<String> = vec![
+        "A".to_string(),
+        "B".to_string(),
+        "D".to_string(),
+        "E".to_string()
+    ];
+    assert_eq!(Ok(expected_path), graph.dag_longest_path(WeightInterpretation::Unweighted, None));
+
+    let expected_weighted_path: Vec<String> = vec![
+        "A".to_string(),
+        "C".to_string(),
+        "D".to_string(),
+        "E".to_string()
+    ];
+    assert_eq!(Ok(expected_weighted_path), graph.dag_longest_path(WeightInterpretation::Distance, None));
+
+    // C-D is a strong tie, so it is the shortest connection
+    let strength_path = graph.dag_longest_path(WeightInterpretation::Strength, None).unwrap();
+    assert_eq!(vec!["A", "B", "D", "E"], strength_path);
+}
+
+#[test]
+fn test_ancestors_and_descendants() {
+    let graph = create_citation_graph();
+
+    let descendants = graph.descendants("B");
+    assert_eq!(2, descendants.len());
+    assert!(descendants.contains("D") && descendants.contains("E"));
+
+    let ancestors = graph.ancestors("D");
+    assert_eq!(3, ancestors.len());
+    assert!(ancestors.contains("A") && ancestors.contains("B") && ancestors.contains("C"));
+}
+
+fn create_main_path_graph() -> _Graph {
+    let mut graph = _Graph::default();
+    for label in ["A", "B", "C", "D", "E", "F", "G"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("A".to_string(), "C".to_string(), 1., Some(true));
+    graph.create_connection("B".to_string(), "C".to_string(), 1., Some(true));
+    graph.create_connection("C".to_string(), "D".to_string(), 1., Some(true));
+    graph.create_connection("C".to_string(), "E".to_string(), 1., Some(true));
+    graph.create_connection("D".to_string(), "F".to_string(), 1., Some(true));
+    graph.create_connection("E".to_string(), "F".to_string(), 1., Some(true));
+    graph.create_connection("E".to_string(), "G".to_string(), 1., Some(true));
+
+    return graph;
+}
+
+fn edge_labels(graph: &mut _Graph) -> Vec<(String, String)> {
+    let mut edges: Vec<(String, String)> = graph.get_connections(None, None, false)
+        .iter()
+        .map(|conn| {
+            let from = match &conn["from"] {
+                ConnectionProperty::From(s) => s.clone(),
+                _ => unreachable!(),
+            };
+            let to = match &conn["to"] {
+                ConnectionProperty::To(s) => s.clone(),
+                _ => unreachable!(),
+            };
+            (from, to)
+        })
+        .collect();
+    edges.sort();
+
+    return edges;
+}
+
+#[test]
+fn test_traversal_weights() {
+    let graph = create_main_path_graph();
+
+    let find = |weights: &Vec<(String, String, f64)>, from: &str, to: &str| -> f64 {
+        weights.iter().find(|(f, t, _)| f == from && t == to).unwrap().2
+    };
+
+    let spc = graph.traversal_weights(TraversalWeight::Spc).unwrap();
+    assert_eq!(3., find(&spc, "A", "C"));
+    assert_eq!(2., find(&spc, "C", "D"));
+    assert_eq!(4., find(&spc, "C", "E"));
+    assert_eq!(2., find(&spc, "E", "G"));
+
+    let splc = graph.traversal_weights(TraversalWeight::Splc).unwrap();
+    assert_eq!(6., find(&splc, "C", "E"));
+
+    let spnp = graph.traversal_weights(TraversalWeight::Spnp).unwrap();
+    assert_eq!(9., find(&spnp, "C", "E"));
+}
+
+#[test]
+fn test_main_paths() {
+    let graph = create_main_path_graph();
+
+    let expected_local: Vec<(String, String)> = vec![
+        ("A".to_string(), "C".to_string()),
+        ("B".to_string(), "C".to_string()),
+        ("C".to_string(), "E".to_string()),
+        ("E".to_string(), "F".to_string()),
+        ("E".to_string(), "G".to_string()),
+    ];
+
+    let mut local = graph.local_main_path(TraversalWeight::Spc).unwrap();
+    assert_eq!(expected_local, edge_labels(&mut local));
+
+    let mut key_route = graph.key_route_main_path(1, TraversalWeight::Spc).unwrap();
+    assert_eq!(expected_local, edge_labels(&mut key_route));
+
+    let expected_global: Vec<(String, String)> = vec![
+        ("A".to_string(), "C".to_string()),
+        ("C".to_string(), "E".to_string()),
+        ("E".to_string(), "F".to_string()),
+    ];
+
+    let mut global = graph.global_main_path(TraversalWeight::Spc).unwrap();
+    assert_eq!(4, global.get_node_count());
+    assert_eq!(expected_global, edge_labels(&mut global));
+}
+
+#[test]
+fn test_main_path_with_cycle() {
+    let mut graph = create_main_path_graph();
+    graph.create_connection("F".to_string(), "C".to_string(), 1., Some(true));
+
+    assert!(graph.local_main_path(TraversalWeight::Spc).is_err());
+}
+
+fn create_author_work_graph() -> _Graph {
+    let mut graph = _Graph::default();
+    for label in ["w1", "w2", "w3", "ana", "bia", "caio"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("w1".to_string(), "ana".to_string(), 1., Some(false));
+    graph.create_connection("w1".to_string(), "bia".to_string(), 1., Some(false));
+    graph.create_connection("w2".to_string(), "ana".to_string(), 1., Some(false));
+    graph.create_connection("w2".to_string(), "bia".to_string(), 1., Some(false));
+    graph.create_connection("w2".to_string(), "caio".to_string(), 1., Some(false));
+    graph.create_connection("w3".to_string(), "caio".to_string(), 1., Some(false));
+
+    return graph;
+}
+
+#[test]
+fn test_is_bipartite() {
+    let mut graph = create_author_work_graph();
+    assert!(graph.is_bipartite());
+
+    let (works, authors) = graph.bipartite_sets().unwrap();
+    assert_eq!(vec!["w1".to_string(), "w2".to_string(), "w3".to_string()], works);
+    assert_eq!(vec!["ana".to_string(), "bia".to_string(), "caio".to_string()], authors);
+
+    graph.create_connection("ana".to_string(), "bia".to_string(), 1., Some(false));
+    assert!(!graph.is_bipartite());
+    assert!(graph.maximum_matching().is_err());
+}
+
+#[test]
+fn test_bipartite_projection() {
+    let graph = create_author_work_graph();
+    let authors = vec!["ana".to_string(), "bia".to_string(), "caio".to_string()];
+
+    let mut simple = graph.bipartite_projection(&authors, ProjectionMethod::Simple);
+    assert_eq!(3, simple.get_node_count());
+    assert_eq!(3, simple.get_edge_count());
+    assert_eq!(2., simple.generate_adjacency_matrix()[0][1]);
+
+    let mut newman = graph.bipartite_projection(&authors, ProjectionMethod::Newman);
+    assert_abs_diff_eq!(1.5, newman.generate_adjacency_matrix()[0][1], epsilon = 1e-6);
+    assert_abs_diff_eq!(0.5, newman.generate_adjacency_matrix()[1][2], epsilon = 1e-6);
+
+    let mut overlap = graph.bipartite_projection(&authors, ProjectionMethod::Overlap);
+    assert_abs_diff_eq!(0.5, overlap.generate_adjacency_matrix()[0][2], epsilon = 1e-6);
+
+    let mut jaccard = graph.bipartite_projection(&authors, ProjectionMethod::Jaccard);
+    assert_abs_diff_eq!(1., jaccard.generate_adjacency_matrix()[0][1], epsilon = 1e-6);
+    assert_abs_diff_eq!(1. / 3., jaccard.generate_adjacency_matrix()[0][2], epsilon = 1e-6);
+}
+
+#[test]
+fn test_maximum_matching() {
+    let graph = create_author_work_graph();
+
+    let matching = graph.maximum_matching().unwrap();
+    assert_eq!(3, matching.len());
+    assert!(matching.contains(&("w3".to_string(), "caio".to_string())));
+}
+
+#[test]
+fn test_maximum_matching_long_augmenting_path() {
+    // Path l0-r0-l1-r1-...: the first phase matches every li with r(i-1),
+    // leaving one augmenting path through the whole graph
+    let n = 100_000;
+    let mut graph = _Graph::default();
+    for i in (0..n).rev() {
+        graph.add_node(format!("l{}", i));
+    }
+    for i in 0..n {
+        graph.add_node(format!("r{}", i));
+    }
+    for i in 0..n {
+        graph.create_connection(format!("l{}", i), format!("r{}", i), 1., Some(false));
+        if i > 0 {
+            graph.create_connection(format!("l{}", i), format!("r{}", i - 1), 1., Some(false));
+        }
+    }
+
+    let matching = graph.maximum_matching().unwrap();
+    assert_eq!(n, matching.len());
+    assert!(matching.contains(&("l0".to_string(), "r0".to_string())));
+}
+
+fn create_graph_from_edges(labels: &[&str], edges: &[(&str, &str, f32)], directed: bool) -> _Graph {
+    let mut graph = _Graph::default();
+    for label in labels {
+        graph.add_node(label.to_string());
+    }
+
+    for (from, to, weight) in edges {
+        graph.create_connection(from.to_string(), to.to_string(), *weight, Some(directed));
+    }
+
+    return graph;
+}
+
+#[test]
+fn test_is_isomorphic() {
+    let square = create_graph_from_edges(&["a", "b", "c", "d"], &[("a", "b", 1.), ("b", "c", 2.), ("c", "d", 1.), ("d", "a", 2.)], false);
+    let relabeled = create_graph_from_edges(&["1", "2", "3", "4"], &[("1", "3", 2.), ("3", "2", 1.), ("2", "4", 2.), ("4", "1", 1.)], false);
+    let star = create_graph_from_edges(&["1", "2", "3", "4"], &[("1", "2", 1.), ("1", "3", 1.), ("1", "4", 1.), ("2", "3", 1.)], false);
+    let directed_square = create_graph_from_edges(&["1", "2", "3", "4"], &[("1", "2", 1.), ("2", "3", 2.), ("3", "4", 1.), ("4", "1", 2.)], true);
+
+    assert!(square.is_isomorphic(&relabeled, None, None));
+    assert!(!square.is_isomorphic(&star, None, None));
+    assert!(!square.is_isomorphic(&directed_square, None, None));
+
+    let same_weight = |a: &Connection, b: &Connection| a.weight == b.weight;
+    assert!(square.is_isomorphic(&relabeled, None, Some(&same_weight)));
+
+    let heavier = create_graph_from_edges(&["1", "2", "3", "4"], &[("1", "2", 1.), ("2", "3", 1.), ("3", "4", 1.), ("4", "1", 2.)], false);
+    assert!(square.is_isomorphic(&heavier, None, None));
+    assert!(!square.is_isomorphic(&heavier, None, Some(&same_weight)));
+}
+
+#[test]
+fn test_subgraph_isomorphisms() {
+    let complete = create_graph_from_edges(
+        &["a", "b", "c", "d"],
+        &[("a", "b", 1.), ("a", "c", 1.), ("a", "d", 1.), ("b", "c", 1.), ("b", "d", 1.), ("c", "d", 1.)],
+        false
+    );
+    let triangle = create_graph_from_edges(&["x", "y", "z"], &[("x", "y", 1.), ("y", "z", 1.), ("z", "x", 1.)], false);
+    let path = create_graph_from_edges(&["x", "y", "z"], &[("x", "y", 1.), ("y", "z", 1.)], false);
+
+    assert_eq!(24, complete.subgraph_isomorphisms(&triangle, true, None, None).len());
+    assert_eq!(0, complete.subgraph_isomorphisms(&path, true, None, None).len());
+    assert_eq!(24, complete.subgraph_isomorphisms(&path, false, None, None).len());
+
+    let center_is_a = |target: &_Node, pattern: &_Node| pattern.label != "y" || target.label == "a";
+    let mappings = complete.subgraph_isomorphisms(&path, false, Some(&center_is_a), None);
+    assert_eq!(6, mappings.len());
+    assert!(mappings.iter().all(|m| m["y"] == "a"));
+}
+
+#[test]
+fn test_is_isomorphic_long_path() {
+    // Every node of the path is one level deeper in the search
+    let n = 100_000;
+    let mut path = _Graph::default();
+    let mut other = _Graph::default();
+    for i in 0..n {
+        path.add_node(format!("a{}", i));
+        other.add_node(format!("b{}", i));
+    }
+    for i in 1..n {
+        path.create_connection(format!("a{}", i - 1), format!("a{}", i), 1., Some(false));
+        other.create_connection(format!("b{}", i), format!("b{}", i - 1), 1., Some(false));
+    }
+
+    assert!(path.is_isomorphic(&other, None, None));
+}
+
+#[test]
+fn test_random_walks() {
+    let graph = create_citation_graph();
+
+    let walks = graph.random_walks(4, 2, false, Some(42));
+    assert_eq!(10, walks.len());
+    assert_eq!(walks, graph.random_walks(4, 2, false, Some(42)));
+
+    // Directed citation graph: walks stop at E, which cites nothing
+    let from_e: Vec<&Vec<String>> = walks.iter().filter(|w| w[0] == "E").collect();
+    assert!(from_e.iter().all(|w| w.len() == 1));
+    assert!(walks.iter().all(|w| w.len() <= 4));
+
+    let from_a = &walks[0];
+    assert_eq!(vec!["A".to_string(), from_a[1].clone(), "D".to_string(), "E".to_string()], *from_a);
+}
+
+#[test]
+fn test_node2vec_walks() {
+    let mut graph = _Graph::default();
+    for label in ["a", "b", "c", "d"] {
+        graph.add_node(label.to_string());
+    }
+    graph.create_connection("a".to_string(), "b".to_string(), 1., Some(false));
+    graph.create_connection("b".to_string(), "c".to_string(), 1., Some(false));
+    graph.create_connection("c".to_string(), "d".to_string(), 1., Some(false));
+
+    // A very small return parameter makes the walk bounce back and forth
+    let walks = graph.node2vec_walks(5, 1, 1e-6, 1., false, Some(3));
+    let expected_walk: Vec<String> = vec!["a", "b", "a", "b", "a"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(expected_walk, walks[0]);
+
+    // A very small in-out parameter pushes it away
+    let walks = graph.node2vec_walks(4, 1, 1., 1e-6, false, Some(3));
+    let expected_walk: Vec<String> = vec!["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(expected_walk, walks[0]);
+}
+
+fn create_coauthorship_graph() -> _Graph {
+    let mut graph = _Graph::default();
+    for label in ["a", "b", "c", "d", "e"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("a".to_string(), "b".to_string(), 1., Some(false));
+    graph.create_connection("a".to_string(), "c".to_string(), 1., Some(false));
+    graph.create_connection("b".to_string(), "d".to_string(), 1., Some(false));
+    graph.create_connection("c".to_string(), "d".to_string(), 1., Some(false));
+    graph.create_connection("d".to_string(), "e".to_string(), 1., Some(false));
+
+    return graph;
+}
+
+#[test]
+fn test_link_prediction() {
+    let graph = create_coauthorship_graph();
+
+    let common = graph.link_prediction(LinkPredictor::CommonNeighbours, None, None, 0.05);
+    assert_eq!(5, common.len());
+    assert_eq!(("a".to_string(), "d".to_string(), 2.), common[0]);
+    assert_eq!(("b".to_string(), "c".to_string(), 2.), common[1]);
+    assert_eq!(("a".to_string(), "e".to_string(), 0.), common[4]);
+
+    let jaccard = graph.link_prediction(LinkPredictor::Jaccard, None, Some(1), 0.05);
+    assert_eq!(vec![("b".to_string(), "c".to_string(), 1.)], jaccard);
+
+    let candidates = vec![("a".to_string(), "d".to_string()), ("b".to_string(), "c".to_string())];
+
+    let adamic_adar = graph.link_prediction(LinkPredictor::AdamicAdar, Some(&candidates), None, 0.05);
+    assert_abs_diff_eq!(2. / 2f64.ln(), adamic_adar[0].2, epsilon = 1e-9);
+    assert_abs_diff_eq!(1. / 2f64.ln() + 1. / 3f64.ln(), adamic_adar[1].2, epsilon = 1e-9);
+
+    let resource = graph.link_prediction(LinkPredictor::ResourceAllocation, Some(&candidates), None, 0.05);
+    assert_abs_diff_eq!(1., resource[0].2, epsilon = 1e-9);
+
+    let preferential = graph.link_prediction(LinkPredictor::PreferentialAttachment, Some(&candidates), None, 0.05);
+    assert_eq!(("a".to_string(), "d".to_string(), 6.), preferential[0]);
+
+    let katz = graph.link_prediction(LinkPredictor::Katz, None, None, 0.1);
+    let score = |a: &str, b: &str| katz.iter().find(|(x, y, _)| x == a && y == b).unwrap().2;
+    assert!(score("a", "d") > score("a", "e"));
+    assert!(score("a", "d") > 2. * 0.01);
+}
+
+#[test]
+fn test_node_similarity() {
+    let graph = create_graph_from_edges(
+        &["X", "Y", "Z", "P", "Q", "R"],
+        &[("X", "P", 1.), ("X", "Q", 1.), ("Y", "P", 1.), ("Y", "Q", 1.), ("Z", "R", 1.)],
+        true
+    );
+
+    let cosine = graph.similarity_matrix(SimilarityMethod::Cosine, 0, 0.);
+    assert_abs_diff_eq!(1., cosine[0][1], epsilon = 1e-9);
+    assert_abs_diff_eq!(0., cosine[0][2], epsilon = 1e-9);
+    assert_abs_diff_eq!(0., cosine[3][3], epsilon = 1e-9);
+
+    let pearson = graph.similarity_matrix(SimilarityMethod::Pearson, 0, 0.);
+    assert_abs_diff_eq!(1., pearson[0][1], epsilon = 1e-9);
+    assert!(pearson[0][2] < 0.);
+
+    let simrank = graph.similarity_matrix(SimilarityMethod::SimRank, 5, 0.8);
+    assert_abs_diff_eq!(1., simrank[3][3], epsilon = 1e-9);
+    assert_abs_diff_eq!(0.4, simrank[3][4], epsilon = 1e-9);
+    assert_abs_diff_eq!(0., simrank[3][5], epsilon = 1e-9);
+    assert_abs_diff_eq!(0., simrank[0][1], epsilon = 1e-9);
+
+    let similar = graph.most_similar(1, SimilarityMethod::SimRank, 5, 0.8);
+    assert_eq!(1, similar["P"].len());
+    assert_eq!("Q", similar["P"][0].0);
+    assert_abs_diff_eq!(0.4, similar["P"][0].1, epsilon = 1e-9);
+    assert!(similar["R"].is_empty());
+
+    let similar = graph.most_similar(5, SimilarityMethod::Cosine, 0, 0.);
+    assert_eq!("Y", similar["X"][0].0);
+    assert_eq!(1, similar["X"].len());
+}
+
+#[test]
+fn test_biconnectivity() {
+    let graph = create_graph_from_edges(
+        &["a", "b", "c", "d", "e", "f", "g", "h"],
+        &[
+            ("a", "b", 1.), ("b", "c", 1.), ("c", "a", 1.), ("c", "d", 1.),
+            ("d", "e", 1.), ("e", "f", 1.), ("f", "d", 1.), ("f", "g", 1.)
+        ],
+        false
+    );
+
+    let articulation_points = graph.articulation_points();
+    assert_eq!(HashSet::from(["c".to_string(), "d".to_string(), "f".to_string()]), articulation_points);
+
+    let bridges = graph.bridges();
+    assert_eq!(vec![("c".to_string(), "d".to_string()), ("f".to_string(), "g".to_string())], bridges);
+
+    let components = graph.biconnected_components();
+    assert_eq!(4, components.len());
+    for expected in [vec!["a", "b", "c"], vec!["c", "d"], vec!["d", "e", "f"], vec!["f", "g"]] {
+        let expected: HashSet<String> = expected.into_iter().map(|l| l.to_string()).collect();
+        assert!(components.contains(&expected));
+    }
+
+    let component_edges = graph.biconnected_component_edges();
+    let triangle = component_edges.iter().find(|edges| edges.len() == 3 && edges.iter().any(|(u, _)| u == "a")).unwrap();
+    assert_eq!(
+        &vec![("a".to_string(), "b".to_string()), ("a".to_string(), "c".to_string()), ("b".to_string(), "c".to_string())],
+        triangle
+    );
+
+    // A second connection between c and d keeps them together if either is removed
+    let mut doubled = graph;
+    doubled.create_connection("d".to_string(), "c".to_string(), 1., Some(true));
+    assert_eq!(vec![("f".to_string(), "g".to_string())], doubled.bridges());
+    assert_eq!(articulation_points, doubled.articulation_points());
+    assert!(doubled.biconnected_component_edges().contains(&vec![("c".to_string(), "d".to_string())]));
+
+    // The parallel p-v connections form their own component without leaking into a-p
+    let mut parallel = create_graph_from_edges(&["a", "p", "v"], &[("a", "p", 1.), ("p", "v", 1.)], false);
+    parallel.create_connection("p".to_string(), "v".to_string(), 1., Some(false));
+    let components = parallel.biconnected_components();
+    assert_eq!(2, components.len());
+    assert!(components.contains(&HashSet::from(["p".to_string(), "v".to_string()])));
+    assert!(components.contains(&HashSet::from(["a".to_string(), "p".to_string()])));
+
+    let mut component_edges = parallel.biconnected_component_edges();
+    component_edges.sort();
+    assert_eq!(
+        vec![vec![("a".to_string(), "p".to_string())], vec![("p".to_string(), "v".to_string())]],
+        component_edges
+    );
+    assert_eq!(vec![("a".to_string(), "p".to_string())], parallel.bridges());
+}
+
+#[test]
+fn test_cliques() {
+    let graph = create_graph_from_edges(
+        &["a", "b", "c", "d", "e", "f", "g", "h", "i"],
+        &[
+            ("a", "b", 1.), ("a", "c", 1.), ("a", "d", 1.), ("b", "c", 1.), ("b", "d", 1.), ("c", "d", 1.),
+            ("d", "e", 1.), ("d", "f", 1.), ("e", "f", 1.), ("e", "g", 1.), ("f", "g", 1.), ("g", "h", 1.)
+        ],
+        false
+    );
+
+    let cliques = graph.maximal_cliques();
+    assert_eq!(5, cliques.len());
+    assert_eq!(vec!["a", "b", "c", "d"], cliques[0]);
+    assert!(cliques.contains(&vec!["d".to_string(), "e".to_string(), "f".to_string()]));
+    assert!(cliques.contains(&vec!["e".to_string(), "f".to_string(), "g".to_string()]));
+    assert!(cliques.contains(&vec!["g".to_string(), "h".to_string()]));
+    assert!(cliques.contains(&vec!["i".to_string()]));
+
+    assert_eq!(4, graph.clique_number());
+
+    let node_clique_number = graph.node_clique_number();
+    assert_eq!(4, node_clique_number["d"]);
+    assert_eq!(3, node_clique_number["e"]);
+    assert_eq!(2, node_clique_number["h"]);
+    assert_eq!(1, node_clique_number["i"]);
+
+    let communities = graph.k_clique_communities(3);
+    assert_eq!(2, communities.len());
+    let as_set = |labels: &[&str]| labels.iter().map(|l| l.to_string()).collect::<HashSet<String>>();
+    assert!(communities.contains(&as_set(&["a", "b", "c", "d"])));
+    assert!(communities.contains(&as_set(&["d", "e", "f", "g"])));
+
+    assert_eq!(1, graph.k_clique_communities(4).len());
+}
+
+#[test]
+fn test_k_shortest_paths() {
+    let graph = create_graph_from_edges(
+        &["C", "D", "E", "F", "G", "H"],
+        &[
+            ("C", "D", 3.), ("C", "E", 2.), ("D", "F", 4.), ("E", "D", 1.), ("E", "F", 2.),
+            ("E", "G", 3.), ("F", "G", 2.), ("F", "H", 1.), ("G", "H", 2.)
+        ],
+        true
+    );
+
+    let paths = graph.k_shortest_paths("C", "H", 3, WeightInterpretation::Distance, None).unwrap();
+    assert_eq!(3, paths.len());
+    assert_eq!((vec!["C", "E", "F", "H"].iter().map(|l| l.to_string()).collect(), 5.), paths[0]);
+    assert_eq!((vec!["C", "E", "G", "H"].iter().map(|l| l.to_string()).collect(), 7.), paths[1]);
+    assert_eq!((vec!["C", "D", "F", "H"].iter().map(|l| l.to_string()).collect(), 8.), paths[2]);
+
+    assert_eq!(7, graph.k_shortest_paths("C", "H", 20, WeightInterpretation::Distance, None).unwrap().len());
+    assert!(graph.k_shortest_paths("H", "C", 2, WeightInterpretation::Distance, None).unwrap().is_empty());
+}
+
+#[test]
+fn test_all_simple_paths() {
+    let graph = create_citation_graph();
+
+    let paths: Vec<Vec<String>> = graph.all_simple_paths("A", "E", None).collect();
+    assert_eq!(vec![vec!["A", "B", "D", "E"], vec!["A", "C", "D", "E"]], paths);
+
+    assert_eq!(0, graph.all_simple_paths("A", "E", Some(2)).count());
+    assert_eq!(2, graph.all_simple_paths("A", "D", Some(2)).count());
+    assert_eq!(0, graph.all_simple_paths("E", "A", None).count());
+
+    let mut lazy = graph.all_simple_paths("A", "E", None);
+    assert_eq!(vec!["A", "B", "D", "E"], lazy.next().unwrap());
+}
+
+#[test]
+fn test_robustness() {
+    let star = create_graph_from_edges(
+        &["c", "l1", "l2", "l3", "l4"],
+        &[("c", "l1", 1.), ("c", "l2", 1.), ("c", "l3", 1.), ("c", "l4", 1.)],
+        false
+    );
+
+    let attack = star.robustness(RemovalTarget::Nodes, RemovalStrategy::Degree, false, None, None, WeightInterpretation::Unweighted, None).unwrap();
+    assert_eq!(vec![0, 1, 2, 3, 4, 5], attack.step);
+    assert_eq!(vec!["c".to_string()], attack.removed[1]);
+    assert_eq!(vec![5, 1, 1, 1, 1, 0], attack.giant_component_size);
+    assert_abs_diff_eq!(1.6, attack.average_path_length[0], epsilon = 1e-9);
+    assert!(attack.average_path_length[1].is_nan());
+    assert_abs_diff_eq!(0.16, attack.r_index, epsilon = 1e-9);
+
+    let betweenness = star.robustness(RemovalTarget::Nodes, RemovalStrategy::Betweenness, true, Some(1), None, WeightInterpretation::Unweighted, None).unwrap();
+    assert_eq!(2, betweenness.step.len());
+    assert_eq!(vec!["c".to_string()], betweenness.removed[1]);
+
+    let path = create_graph_from_edges(&["a", "b", "c", "d"], &[("a", "b", 1.), ("b", "c", 1.), ("c", "d", 1.)], false);
+    let edges = path.robustness(RemovalTarget::Edges, RemovalStrategy::Degree, false, None, None, WeightInterpretation::Unweighted, None).unwrap();
+    assert_eq!(vec!["b".to_string(), "c".to_string()], edges.removed[1]);
+    assert_eq!(vec![4, 2, 2, 1], edges.giant_component_size);
+    assert_abs_diff_eq!(1. / 3., edges.fraction_removed[1], epsilon = 1e-9);
+
+    let first = path.robustness(RemovalTarget::Nodes, RemovalStrategy::Random, false, None, Some(7), WeightInterpretation::Unweighted, None).unwrap();
+    let second = path.robustness(RemovalTarget::Nodes, RemovalStrategy::Random, false, None, Some(7), WeightInterpretation::Unweighted, None).unwrap();
+    assert_eq!(first.removed, second.removed);
+
+    let weighted = create_graph_from_edges(&["a", "b", "c"], &[("a", "b", 1.), ("b", "c", 3.)], false);
+    let distance = weighted.robustness(RemovalTarget::Nodes, RemovalStrategy::Degree, false, Some(0), None, WeightInterpretation::Distance, None).unwrap();
+    assert_abs_diff_eq!(8. / 3., distance.average_path_length[0], epsilon = 1e-9);
+
+    let doubled = |w: f32| (w * 2.) as f64;
+    let custom = weighted.robustness(RemovalTarget::Nodes, RemovalStrategy::Degree, false, Some(0), None, WeightInterpretation::Distance, Some(&doubled)).unwrap();
+    assert_abs_diff_eq!(16. / 3., custom.average_path_length[0], epsilon = 1e-9);
+}
+
+#[test]
+fn test_sampling() {
+    let mut graph = _Graph::default();
+    for i in 0..20 {
+        graph.add_node_with_pos(i.to_string(), i as f64, -(i as f64));
+    }
+    for i in 0..20 {
+        graph.create_connection(i.to_string(), ((i + 1) % 20).to_string(), 2., Some(true));
+    }
+
+    let samples = vec![
+        graph.random_node_sample(8, Some(3)),
+        graph.random_edge_sample(8, Some(3)),
+        graph.snowball_sample(8, None, None, Some(3)),
+        graph.random_walk_sample(8, 0.15, Some(3)),
+        graph.forest_fire_sample(8, 0.7, Some(3)),
+    ];
+
+    for sample in &samples {
+        assert_eq!(8, sample.get_node_count());
+        for node in &sample.nodes {
+            let node = node.borrow();
+            let i: f64 = node.label.parse().unwrap();
+            assert_eq!((Some(i), Some(-i)), (node.x, node.y));
+
+            for conn in &node.connections {
+                assert_eq!(2., conn.weight);
+                assert!(conn.directed);
+            }
+        }
+    }
+
+    let first = graph.forest_fire_sample(8, 0.7, Some(11));
+    let second = graph.forest_fire_sample(8, 0.7, Some(11));
+    let labels = |g: &_Graph| g.nodes.iter().map(|n| n.borrow().label.clone()).collect::<Vec<String>>();
+    assert_eq!(labels(&first), labels(&second));
+
+    // Two hops around "5" on the ring are "3" to "7"
+    let snowball = graph.snowball_sample(5, Some(2), Some("5"), None);
+    assert_eq!(vec!["3", "4", "5", "6", "7"], labels(&snowball));
+    assert_eq!(4, snowball.nodes.iter().map(|n| n.borrow().connections.len()).sum::<usize>());
+    assert_eq!(10, graph.snowball_sample(10, Some(2), Some("5"), None).get_node_count());
+    assert_eq!(20, graph.random_node_sample(100, None).get_node_count());
+}
+
+#[test]
+fn test_quotient_graph() {
+    let mut graph = _Graph::default();
+    graph.add_node_with_pos("a1".to_string(), 0., 0.);
+    graph.add_node_with_pos("a2".to_string(), 2., 4.);
+    graph.add_node_with_pos("b1".to_string(), 10., 10.);
+    graph.add_node_with_pos("c1".to_string(), 5., 5.);
+
+    graph.create_connection("a1".to_string(), "a2".to_string(), 1., Some(false));
+    graph.create_connection("a1".to_string(), "b1".to_string(), 2., Some(false));
+    graph.create_connection("b1".to_string(), "a2".to_string(), 4., Some(false));
+    graph.create_connection("a2".to_string(), "b1".to_string(), 3., Some(true));
+    graph.create_connection("c1".to_string(), "a1".to_string(), 5., Some(true));
+
+    let groups: HashMap<String, String> = HashMap::from([
+        ("a1".to_string(), "A".to_string()),
+        ("a2".to_string(), "A".to_string()),
+        ("b1".to_string(), "B".to_string()),
+    ]);
+
+    let summed = graph.quotient_graph(&groups, EdgeAggregation::Sum, false).unwrap();
+    let labels: Vec<String> = summed.nodes.iter().map(|n| n.borrow().label.clone()).collect();
+    assert_eq!(vec!["A", "B", "c1"], labels);
+    assert_eq!((Some(1.), Some(2.)), (summed.nodes[0].borrow().x, summed.nodes[0].borrow().y));
+
+    let edges = |g: &_Graph| -> Vec<(String, String, f32, bool)> {
+        let mut edges = Vec::new();
+        for n in &g.nodes {
+            for conn in &n.borrow().connections {
+                let to = conn.node.upgrade().unwrap().borrow().label.clone();
+                edges.push((n.borrow().label.clone(), to, conn.weight, conn.directed));
+            }
+        }
+        edges
+    };
+
+    assert_eq!(
+        vec![
+            ("A".to_string(), "B".to_string(), 6., false),
+            ("A".to_string(), "B".to_string(), 3., true),
+            ("c1".to_string(), "A".to_string(), 5., true),
+        ],
+        edges(&summed)
+    );
+
+    let counted = graph.quotient_graph(&groups, EdgeAggregation::Count, true).unwrap();
+    assert_eq!(("A".to_string(), "A".to_string(), 1., false), edges(&counted)[0]);
+    assert_eq!(2., edges(&counted)[1].2);
+
+    let mean = graph.quotient_graph(&groups, EdgeAggregation::Mean, false).unwrap();
+    assert_eq!(3., edges(&mean)[0].2);
+
+    // "c1" keeps a group of its own, which cannot share its name with group "c1"
+    let colliding: HashMap<String, String> = HashMap::from([
+        ("a1".to_string(), "c1".to_string()),
+        ("a2".to_string(), "c1".to_string()),
+    ]);
+    match graph.quotient_graph(&colliding, EdgeAggregation::Sum, false) {
+        Err(e) => assert_eq!("c1", e.label),
+        Ok(_) => panic!("the singleton group of c1 was merged into group c1")
+    }
+}
+
+#[test]
+fn test_backbone() {
+    let star = create_graph_from_edges(
+        &["c", "l1", "l2", "l3", "l4", "l5"],
+        &[("c", "l1", 10.), ("c", "l2", 1.), ("c", "l3", 1.), ("c", "l4", 1.), ("c", "l5", 1.)],
+        false
+    );
+
+    let (disparity, p_values) = star.backbone(BackboneMethod::DisparityFilter, None).unwrap();
+    assert_eq!(2, disparity.get_node_count());
+    assert_abs_diff_eq!((4. / 14f64).powi(4), p_values[0].2, epsilon = 1e-9);
+    assert_abs_diff_eq!((13. / 14f64).powi(4), p_values[1].2, epsilon = 1e-9);
+
+    let (global, weights) = star.backbone(BackboneMethod::GlobalThreshold, Some(2.)).unwrap();
+    assert_eq!(1, global.nodes[0].borrow().connections.len());
+    assert_eq!(("c".to_string(), "l1".to_string(), 10.), weights[0]);
+
+    // Two strongly tied pairs joined by weak connections
+    let pairs = create_graph_from_edges(
+        &["a", "b", "c", "d"],
+        &[("a", "b", 10.), ("c", "d", 10.), ("a", "c", 1.), ("b", "d", 1.)],
+        false
+    );
+    let (mut noise_corrected, scores) = pairs.backbone(BackboneMethod::NoiseCorrected, None).unwrap();
+    assert!(scores[0].2 > 1.64 && scores[1].2 < 0.);
+    assert_eq!(2, noise_corrected.get_edge_count());
+
+    // The weak a-c connection is never on a shortest path
+    let triangle = create_graph_from_edges(&["a", "b", "c"], &[("a", "b", 5.), ("b", "c", 5.), ("a", "c", 1.)], false);
+    let (mut skeleton, salience) = triangle.backbone(BackboneMethod::HighSalienceSkeleton, None).unwrap();
+    let saliences: Vec<f64> = salience.iter().map(|s| s.2).collect();
+    assert_eq!(vec![1., 0., 1.], saliences);
+    assert_eq!(3, skeleton.get_node_count());
+    assert_eq!(2, skeleton.get_edge_count());
+}
+
+#[test]
+fn test_temporal_graph() {
+    let mut graph = _Graph::default();
+    for label in ["A", "B", "C", "D"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_temporal_connection("A".to_string(), "B".to_string(), 1., Some(false), 1., None);
+    graph.create_temporal_connection("B".to_string(), "C".to_string(), 1., Some(false), 2., Some(4.));
+    graph.create_temporal_connection("C".to_string(), "D".to_string(), 1., Some(true), 3., None);
+    graph.create_temporal_connection("D".to_string(), "A".to_string(), 1., Some(false), 0., None);
+
+    assert_eq!(Some((0., 4.)), graph.time_span());
+
+    let mut snapshot = graph.snapshot(3.);
+    assert_eq!(4, snapshot.get_node_count());
+    assert_eq!(2, snapshot.get_edge_count());
+    assert_eq!(Some((2., 4.)), snapshot.time_span());
+
+    // B-C starts at the end of the window, which is excluded
+    assert_eq!(2, graph.window(0., 2.).get_edge_count());
+
+    let windows: Vec<(f64, f64, usize)> = graph
+        .sliding_windows(2., None, None, None)
+        .map(|(start, end, mut g)| (start, end, g.get_edge_count()))
+        .collect();
+    assert_eq!(vec![(0., 2., 2), (2., 4., 2), (4., 6., 1)], windows);
+
+    let reached = graph.temporal_reachability("A", None);
+    assert_eq!(0., reached["D"]);
+    assert_eq!(2., reached["C"]);
+
+    // Too late for D-A, so D can only be reached through C
+    let reached = graph.temporal_reachability("A", Some(0.5));
+    assert_eq!(3., reached["D"]);
+    assert_eq!(
+        Some(vec![
+            ("A".to_string(), "B".to_string(), 1.),
+            ("B".to_string(), "C".to_string(), 2.),
+            ("C".to_string(), "D".to_string(), 3.)
+        ]),
+        graph.time_respecting_path("A", "D", Some(0.5))
+    );
+
+    let reached = graph.temporal_reachability("C", Some(3.5));
+    assert_eq!(HashSet::from(["B".to_string(), "C".to_string()]), reached.keys().cloned().collect::<HashSet<String>>());
+    assert_eq!(None, graph.time_respecting_path("C", "A", Some(3.5)));
+    assert_eq!(Some(vec![]), graph.time_respecting_path("C", "C", None));
+
+    // Derived graphs keep the times of their connections
+    let sample = graph.random_node_sample(4, Some(1));
+    assert_eq!(Some((0., 4.)), sample.time_span());
+    assert_eq!(2, sample.snapshot(3.).get_edge_count());
+}
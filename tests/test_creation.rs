@@ -17,7 +17,7 @@ fn make_conn(from: &str, to: &str, weight: f32, directed: bool) -> HashMap<Strin
 #[ignore]
 fn test_from_api() {
     let style = GraphStyle::default();
-    let mut g = _Graph::from_openalex(None,None,None, Some("TEST"), None, OpenAlexGraphType::Coauthorship, "YOUR_API_KEY", Some(100), Some(1.));
+    let mut g = _Graph::from_openalex(None,None,None, Some("TEST"), None, OpenAlexGraphType::Coauthorship, "YOUR_API_KEY", Some(100), Some(1.), None);
     g.output_html("teste_open_alex.html", Layout::Spring, true, style);
 }
 
@@ -86,6 +86,22 @@ fn test_edge_count() {
     assert_eq!(3, g.get_edge_count());
 }
 
+#[test]
+fn test_edges() {
+    let graph = create_simple_graph();
+
+    let edges: Vec<EdgeRef> = graph.edges().collect();
+    assert_eq!(5, edges.len());
+    assert_eq!(EdgeRef { from: 0, to: 1, weight: 2., directed: false, time: None }, edges[0]);
+    assert_eq!(EdgeRef { from: 1, to: 2, weight: 1.6, directed: false, time: None }, edges[1]);
+    assert_eq!(EdgeRef { from: 2, to: 3, weight: 4., directed: true, time: None }, edges[2]);
+    assert_eq!(EdgeRef { from: 2, to: 1, weight: 1.2, directed: true, time: None }, edges[3]);
+    assert_eq!(EdgeRef { from: 3, to: 0, weight: 5.5, directed: false, time: None }, edges[4]);
+
+    let connections = create_simple_graph().get_connections(None, None, false);
+    assert_eq!(connections.len(), graph.edges().count());
+}
+
 #[test]
 fn test_density() {
     let mut grafo = create_simple_graph();
@@ -320,3 +336,248 @@ fn test_get_skewness() {
     assert_abs_diff_eq!(skewness["undirected_skewness"], 0.8666, epsilon = 1e-4);
 
 }
+
+fn create_graph_from_edges(labels: &[&str], edges: &[(&str, &str)], directed: bool) -> _Graph {
+    let mut graph = _Graph::default();
+    for label in labels {
+        graph.add_node(label.to_string());
+    }
+
+    for (from, to) in edges {
+        graph.create_connection(from.to_string(), to.to_string(), 1., Some(directed));
+    }
+
+    return graph;
+}
+
+#[test]
+fn test_triad_census() {
+    let mut graph = create_graph_from_edges(&["a", "b", "c", "d"], &[("a", "b"), ("b", "a"), ("b", "c"), ("c", "a")], true);
+    graph.add_node("e".to_string());
+
+    let census = graph.triad_census();
+
+    assert_eq!(census.len(), 16);
+    assert_eq!(census.values().sum::<f64>(), 10.);
+    assert_eq!(census["120C"], 1.);
+    assert_eq!(census["120U"], 0.);
+    assert_eq!(census["102"], 2.);
+    assert_eq!(census["012"], 4.);
+    assert_eq!(census["003"], 3.);
+}
+
+#[test]
+fn test_motif_census() {
+    let path = create_graph_from_edges(&["a", "b", "c", "d"], &[("a", "b"), ("b", "c"), ("c", "d")], false);
+    assert_eq!(path.motif_census(3)["open_triad"], 2.);
+    assert_eq!(path.motif_census(3)["triangle"], 0.);
+    assert_eq!(path.motif_census(4)["path"], 1.);
+
+    let paw = create_graph_from_edges(&["a", "b", "c", "d"], &[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")], false);
+    assert_eq!(paw.motif_census(3)["triangle"], 1.);
+    assert_eq!(paw.motif_census(3)["open_triad"], 2.);
+    assert_eq!(paw.motif_census(4)["tailed_triangle"], 1.);
+
+    let complete = create_graph_from_edges(
+        &["a", "b", "c", "d", "e"],
+        &[("a", "b"), ("a", "c"), ("a", "d"), ("b", "c"), ("b", "d"), ("c", "d"), ("d", "e")],
+        false
+    );
+    let size4 = complete.motif_census(4);
+    assert_eq!(size4["clique"], 1.);
+    assert_eq!(size4["tailed_triangle"], 3.);
+    assert_eq!(size4.values().sum::<f64>(), 4.);
+}
+
+#[test]
+fn test_motif_z_scores() {
+    let graph = create_clique_ring_graph();
+
+    let z_scores = graph.motif_z_scores(MotifCensus::Size3, 50, NullModel::Gnm, Some(7));
+    assert!(z_scores["triangle"] > 2.);
+    assert!(z_scores["open_triad"] < z_scores["triangle"]);
+    assert_eq!(z_scores, graph.motif_z_scores(MotifCensus::Size3, 50, NullModel::Gnm, Some(7)));
+
+    let z_scores = graph.motif_z_scores(MotifCensus::Size3, 50, NullModel::DegreePreserving, Some(7));
+    assert!(z_scores["triangle"] > 2.);
+}
+
+// Ring of 8 cliques with 4 nodes each, much more clustered than a random graph
+fn create_clique_ring_graph() -> _Graph {
+    let mut graph = _Graph::default();
+    for group in 0..8 {
+        for member in 0..4 {
+            graph.add_node(format!("{}-{}", group, member));
+        }
+    }
+
+    for group in 0..8 {
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                graph.create_connection(format!("{}-{}", group, i), format!("{}-{}", group, j), 1., Some(false));
+            }
+        }
+        graph.create_connection(format!("{}-0", group), format!("{}-1", (group + 1) % 8), 1., Some(false));
+    }
+
+    return graph;
+}
+
+#[test]
+fn test_double_edge_swap() {
+    let mut graph = create_clique_ring_graph();
+    let mut rewired = graph.double_edge_swap(None, Some(3));
+
+    assert_eq!(graph.get_node_count(), rewired.get_node_count());
+    assert_eq!(graph.get_edge_count(), rewired.get_edge_count());
+    assert_eq!(graph.get_all_nodes_degrees(), rewired.get_all_nodes_degrees());
+    assert_ne!(graph.generate_adjacency_matrix(), rewired.generate_adjacency_matrix());
+    assert!(rewired.motif_census(3)["triangle"] < graph.motif_census(3)["triangle"]);
+
+    let mut unchanged = graph.directed_edge_swap(None, Some(3));
+    assert_eq!(graph.generate_adjacency_matrix(), unchanged.generate_adjacency_matrix());
+}
+
+#[test]
+fn test_directed_edge_swap() {
+    let mut graph = _Graph::default();
+    for i in 0..12 {
+        graph.add_node(i.to_string());
+    }
+    for i in 0..12 {
+        graph.create_connection(i.to_string(), ((i + 1) % 12).to_string(), 1., Some(true));
+        graph.create_connection(i.to_string(), ((i + 3) % 12).to_string(), 2., Some(true));
+    }
+
+    let mut rewired = graph.directed_edge_swap(Some(20), Some(11));
+    assert_eq!(graph.get_all_nodes_degrees(), rewired.get_all_nodes_degrees());
+    assert_ne!(graph.generate_adjacency_matrix(), rewired.generate_adjacency_matrix());
+    assert_eq!(graph.get_total_weight(), rewired.get_total_weight());
+}
+
+#[test]
+fn test_significance() {
+    let graph = create_clique_ring_graph();
+
+    let triangles = graph.significance(&mut |g: _Graph| g.motif_census(3)["triangle"], 30, Some(5));
+    assert_eq!(32., triangles.observed);
+    assert!(triangles.z_score > 2.);
+    assert_abs_diff_eq!(1. / 31., triangles.p_value, epsilon = 1e-9);
+
+    let edges = graph.significance(&mut |mut g: _Graph| g.get_edge_count() as f64, 10, Some(5));
+    assert!(edges.z_score.is_nan());
+    assert_eq!(1., edges.p_value);
+}
+
+#[test]
+fn test_node_metrics() {
+    let mut graph = create_simple_graph();
+    let metrics = vec!["degree".to_string(), "strength".to_string(), "centrality".to_string()];
+    let table = graph.node_metrics(&metrics).unwrap();
+
+    assert_eq!(vec!["node1", "node2", "node3", "node4"], table.label);
+    assert!(table.clustering.is_none());
+
+    for (i, label) in table.label.clone().iter().enumerate() {
+        let degrees = graph.compute_degrees(label);
+        assert_eq!(degrees["in_degree"], table.in_degree.as_ref().unwrap()[i]);
+        assert_eq!(degrees["out_degree"], table.out_degree.as_ref().unwrap()[i]);
+        assert_eq!(degrees["total_degree"], table.total_degree.as_ref().unwrap()[i]);
+        assert_eq!(degrees["undirected_degree"], table.undirected_degree.as_ref().unwrap()[i]);
+
+        let strength = graph.get_node_strength(label);
+        assert_abs_diff_eq!(strength["in_strength"], table.in_strength.as_ref().unwrap()[i], epsilon = 1e-6);
+        assert_abs_diff_eq!(strength["out_strength"], table.out_strength.as_ref().unwrap()[i], epsilon = 1e-6);
+        assert_abs_diff_eq!(strength["total_strength"], table.total_strength.as_ref().unwrap()[i], epsilon = 1e-6);
+
+        let centrality = graph.get_centrality_degrees(label);
+        assert_abs_diff_eq!(centrality["undirected_centrality"], table.undirected_centrality.as_ref().unwrap()[i], epsilon = 1e-6);
+    }
+
+    // Triangle a-b-c with a tail c-d
+    let tailed = create_graph_from_edges(&["a", "b", "c", "d"], &[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")], false);
+    let structure = tailed.node_metrics(&vec!["clustering".to_string(), "betweenness".to_string(), "closeness".to_string()]).unwrap();
+    assert_eq!(vec![1., 1., 1. / 3., 0.], structure.clustering.unwrap());
+    assert_eq!(vec![0., 0., 2., 0.], structure.betweenness.unwrap());
+    assert_abs_diff_eq!(0.75, structure.closeness.unwrap()[0], epsilon = 1e-9);
+    assert!(structure.in_degree.is_none());
+
+    assert!(tailed.node_metrics(&vec!["pagerank".to_string()]).is_err());
+}
+
+#[test]
+fn test_describe() {
+    let mut graph = create_simple_graph();
+    graph.add_node("node5".to_string());
+    graph.create_connection("node5".to_string(), "node5".to_string(), 3., Some(false));
+    graph.create_connection("node1".to_string(), "node2".to_string(), 1., Some(false));
+
+    let summary = graph.describe();
+    assert_eq!(5, summary.node_count);
+    assert_eq!(7, summary.edge_count);
+    assert_eq!(2, summary.directed_edge_count);
+    assert_eq!(5, summary.undirected_edge_count);
+    assert_abs_diff_eq!(12. / 20., summary.density, epsilon = 1e-9);
+    assert_eq!(Some(1.), summary.weight_min);
+    assert_eq!(Some(5.5), summary.weight_max);
+    assert_abs_diff_eq!(2., summary.weight_median.unwrap(), epsilon = 1e-6);
+    assert_eq!((Some(2), Some(4)), (summary.degree_min, summary.degree_max));
+    assert_abs_diff_eq!(14. / 5., summary.degree_mean.unwrap(), epsilon = 1e-9);
+    assert_eq!(2, summary.weakly_connected_components);
+    assert_eq!(2, summary.strongly_connected_components);
+    assert_eq!((1, 1, 0), (summary.self_loop_count, summary.multi_edge_count, summary.isolated_nodes));
+    assert_eq!(None, summary.build_time_ms);
+
+    let text = summary.to_string();
+    assert!(text.contains("7 (2 directed, 5 undirected)"));
+
+    let empty = _Graph::default().describe();
+    assert_eq!(None, empty.weight_mean);
+    assert_eq!(0, empty.weakly_connected_components);
+    // NaN weights are sorted after every number instead of panicking
+    graph.create_connection("node1".to_string(), "node3".to_string(), f32::NAN, Some(false));
+    let with_nan = graph.describe();
+    assert_eq!(Some(1.), with_nan.weight_min);
+    assert!(with_nan.weight_max.unwrap().is_nan());
+
+    // Rounding to four decimals leaves no trailing point
+    let mut almost_two = _Graph::default();
+    almost_two.add_node("a".to_string());
+    almost_two.add_node("b".to_string());
+    almost_two.create_connection("a".to_string(), "b".to_string(), 2.00001, Some(false));
+    assert!(almost_two.describe().to_string().contains("min 2, max 2,"));
+}
+
+#[test]
+fn test_power_law_fit() {
+    // Quantiles of a discrete power law with alpha = 2.5 and of a geometric distribution
+    let n = 1000;
+    let quantile = |i: usize| (i as f64 + 0.5) / n as f64;
+    let power_law: Vec<usize> = (0..n).map(|i| (0.5 * (1. - quantile(i)).powf(-1. / 1.5) + 0.5).floor() as usize).collect();
+    let geometric: Vec<usize> = (0..n).map(|i| 1 + (-(1. - quantile(i)).ln() / 0.3).floor() as usize).collect();
+
+    let fit = netfog::algorithms::power_law::fit_power_law(&power_law, None, 10, Some(1)).unwrap();
+    assert_abs_diff_eq!(2.5, fit.alpha, epsilon = 0.15);
+    assert!(fit.ks_statistic < 0.05);
+    assert!(fit.p_value > 0.1);
+    assert!(fit.exponential.ratio > 0.);
+    assert!(fit.truncated_power_law.p_value > 0.1);
+
+    let fit = netfog::algorithms::power_law::fit_power_law(&geometric, Some(1), 10, Some(1)).unwrap();
+    assert_eq!((1, n), (fit.xmin, fit.n_tail));
+    assert!(fit.p_value < 0.1);
+    assert!(fit.exponential.ratio < 0. && fit.exponential.p_value < 0.01);
+    assert_abs_diff_eq!(0.3, fit.exponential.parameters["lambda"], epsilon = 0.02);
+
+    let mut star = _Graph::default();
+    for i in 0..6 {
+        star.add_node(i.to_string());
+    }
+    for i in 1..6 {
+        star.create_connection("0".to_string(), i.to_string(), 1., Some(true));
+    }
+    assert!(star.power_law_fit(DegreeType::In, None, 0, None).is_err());
+    let fit = star.power_law_fit(DegreeType::All, None, 0, None).unwrap();
+    assert_eq!((1, 6), (fit.xmin, fit.n_tail));
+    assert!(fit.p_value.is_nan());
+}
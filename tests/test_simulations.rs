@@ -0,0 +1,136 @@
+use netfog::*;
+
+fn create_path_graph(directed: bool) -> _Graph {
+    let mut graph = _Graph::default();
+    for label in ["A", "B", "C", "D", "E"] {
+        graph.add_node(label.to_string());
+    }
+
+    graph.create_connection("A".to_string(), "B".to_string(), 1., Some(directed));
+    graph.create_connection("B".to_string(), "C".to_string(), 1., Some(directed));
+    graph.create_connection("C".to_string(), "D".to_string(), 1., Some(directed));
+    graph.create_connection("D".to_string(), "E".to_string(), 1., Some(directed));
+
+    return graph;
+}
+
+#[test]
+fn test_sir() {
+    let graph = create_path_graph(false);
+    let seeds = vec!["C".to_string()];
+
+    let certain = graph.sir(&seeds, 1., 1., 100, false, Some(1));
+    assert_eq!(vec![0, 1, 2, 3], certain.step);
+    assert_eq!(vec![1, 2, 2, 0], certain.infected);
+    assert_eq!(vec![0, 1, 3, 5], certain.recovered);
+    assert_eq!(vec![4, 2, 0, 0], certain.susceptible);
+    assert_eq!(0, certain.infection_times["C"]);
+    assert_eq!(1, certain.infection_times["B"]);
+    assert_eq!(2, certain.infection_times["E"]);
+
+    let blocked = graph.sir(&seeds, 0., 1., 100, false, Some(1));
+    assert_eq!(1, blocked.total_infected());
+
+    let first = graph.sir(&seeds, 0.4, 0.3, 100, false, Some(5));
+    let second = graph.sir(&seeds, 0.4, 0.3, 100, false, Some(5));
+    assert_eq!(first.infected, second.infected);
+    assert_eq!(first.infection_times, second.infection_times);
+
+    for step in 0..first.step.len() {
+        assert_eq!(5, first.susceptible[step] + first.infected[step] + first.recovered[step]);
+    }
+}
+
+#[test]
+fn test_sis() {
+    let graph = create_path_graph(false);
+    let run = graph.sis(&vec!["A".to_string()], 1., 1., 4, false, Some(3));
+
+    assert_eq!(5, run.step.len());
+    assert!(run.recovered.iter().all(|r| *r == 0));
+    assert_eq!(vec![1, 1, 2, 2, 3], run.infected);
+    assert_eq!(4, run.infection_times["E"]);
+}
+
+#[test]
+fn test_independent_cascade() {
+    let graph = create_path_graph(true);
+
+    let full = graph.independent_cascade(&vec!["B".to_string()], 1., false, None, Some(1));
+    assert_eq!(vec![1, 2, 3, 4], full.infected);
+    assert!(!full.infection_times.contains_key("A"));
+    assert_eq!(3, full.infection_times["E"]);
+
+    let limited = graph.independent_cascade(&vec!["B".to_string()], 1., false, Some(1), Some(1));
+    assert_eq!(2, limited.total_infected());
+
+    let weighted = graph.independent_cascade(&vec!["A".to_string()], 0., true, None, Some(1));
+    assert_eq!(5, weighted.total_infected());
+}
+
+#[test]
+fn test_linear_threshold() {
+    let mut graph = create_path_graph(true);
+    graph.add_node("F".to_string());
+    graph.create_connection("F".to_string(), "E".to_string(), 1., Some(true));
+
+    let run = graph.linear_threshold(&vec!["A".to_string()], Some(0.6), false, None, None);
+    assert_eq!(4, run.total_infected());
+    assert_eq!(3, run.infection_times["D"]);
+    assert!(!run.infection_times.contains_key("E"));
+
+    let both = graph.linear_threshold(&vec!["A".to_string(), "F".to_string()], Some(1.), false, None, None);
+    assert_eq!(4, both.infection_times["E"]);
+}
+
+fn create_two_stars_graph() -> _Graph {
+    let mut graph = _Graph::default();
+    for label in ["hub1", "a1", "a2", "a3", "a4", "hub2", "b1", "b2", "b3", "x"] {
+        graph.add_node(label.to_string());
+    }
+
+    for leaf in ["a1", "a2", "a3", "a4"] {
+        graph.create_connection("hub1".to_string(), leaf.to_string(), 1., Some(true));
+    }
+    for leaf in ["b1", "b2", "b3"] {
+        graph.create_connection("hub2".to_string(), leaf.to_string(), 1., Some(true));
+    }
+
+    return graph;
+}
+
+#[test]
+fn test_zero_weight_spreading() {
+    let mut graph = create_path_graph(true);
+    graph.add_node("F".to_string());
+    graph.create_connection("E".to_string(), "F".to_string(), 0., Some(true));
+    let seeds = vec!["E".to_string()];
+
+    // F only receives zero-weight connections: its share is 0, not NaN
+    let cascade = graph.independent_cascade(&seeds, 0.5, true, None, Some(1));
+    assert_eq!(1, cascade.total_infected());
+
+    let threshold = graph.linear_threshold(&seeds, Some(0.5), true, None, Some(1));
+    assert_eq!(1, threshold.total_infected());
+
+    let (chosen, _) = graph.influence_maximization(2, CascadeModel::LinearThreshold, SeedSelection::Celf, 10, 0.1, true, Some(1));
+    assert_eq!(2, chosen.len());
+}
+
+#[test]
+fn test_influence_maximization() {
+    let graph = create_two_stars_graph();
+
+    for method in [SeedSelection::Greedy, SeedSelection::Celf, SeedSelection::CelfPlusPlus] {
+        let (seeds, spread) = graph.influence_maximization(2, CascadeModel::IndependentCascade, method, 10, 1., false, Some(1));
+        assert_eq!(vec!["hub1".to_string(), "hub2".to_string()], seeds);
+        assert_eq!(9., spread);
+    }
+
+    let (seeds, spread) = graph.influence_maximization(1, CascadeModel::LinearThreshold, SeedSelection::Celf, 10, 0.1, false, Some(1));
+    assert_eq!(vec!["hub1".to_string()], seeds);
+    assert_eq!(5., spread);
+
+    let (seeds, _) = graph.influence_maximization(2, CascadeModel::IndependentCascade, SeedSelection::DegreeDiscount, 10, 0.1, false, Some(1));
+    assert_eq!(vec!["hub1".to_string(), "hub2".to_string()], seeds);
+}